@@ -1,5 +1,6 @@
 import os
 import sys
+import signal
 import asyncio
 import logging
 import discord
@@ -8,7 +9,7 @@ from discord.ext import commands
 # Configure enhanced logging FIRST (before any imports that might use logging)
 logging.basicConfig(
     level=logging.INFO,
-    format='%(asctime)s | %(levelname)-8s | %(message)s',
+    format='%(asctime)s | %(levelname)-8s | %(trace_id)s | %(message)s',
     datefmt='%Y-%m-%d %H:%M:%S'
 )
 logger = logging.getLogger(__name__)
@@ -16,32 +17,69 @@ logger = logging.getLogger(__name__)
 # Add the parent directory to sys.path to allow imports
 sys.path.insert(0, os.path.dirname(os.path.dirname(os.path.abspath(__file__))))
 
-# Load .env file if it exists
+# Correlate log lines of one interaction via a trace ID (must run before the first log call)
+from src.tracing import install_log_filter, init_telemetry, configure_log_format
+install_log_filter()
+configure_log_format()
+
+def load_env():
+    """Load .env file if it exists"""
+    try:
+        from dotenv import load_dotenv
+        # Look for .env in the project root (parent of src/)
+        env_path = os.path.join(os.path.dirname(os.path.dirname(os.path.abspath(__file__))), '.env')
+        if os.path.exists(env_path):
+            load_dotenv(env_path)
+            logger.info(f"✅ Loaded environment variables from {env_path}")
+        else:
+            # Try current directory as fallback
+            load_dotenv()
+            logger.info("✅ Loaded environment variables from .env")
+    except ImportError:
+        logger.warning("⚠️  python-dotenv not installed. Install with: pip install python-dotenv")
+    except Exception as e:
+        logger.info(f"ℹ️  No .env file found or error loading: {e}")
+
+load_env()
+
+from src.config import load_config_with_retry, set_config
+from src.errors import ConfigError
+
+# Load configuration (via ENV variable CONFIG_PATH, default: config.yaml)
+# In containers the config file or .env may be mounted shortly after start, so retry a few times
+config_path = os.getenv("CONFIG_PATH", "config.yaml")
 try:
-    from dotenv import load_dotenv
-    # Look for .env in the project root (parent of src/)
-    env_path = os.path.join(os.path.dirname(os.path.dirname(os.path.abspath(__file__))), '.env')
-    if os.path.exists(env_path):
-        load_dotenv(env_path)
-        logger.info(f"✅ Loaded environment variables from {env_path}")
-    else:
-        # Try current directory as fallback
-        load_dotenv()
-        logger.info("✅ Loaded environment variables from .env")
-except ImportError:
-    logger.warning("⚠️  python-dotenv not installed. Install with: pip install python-dotenv")
-except Exception as e:
-    logger.info(f"ℹ️  No .env file found or error loading: {e}")
-
-from src.config import load_config
-from src.commands.radio import RadioCog
+    config = load_config_with_retry(
+        config_path,
+        max_attempts=int(os.getenv("ALASTOR_STARTUP_RETRIES", "5")),
+        delay=float(os.getenv("ALASTOR_STARTUP_DELAY_SECS", "2")),
+        required_env=("DISCORD_TOKEN",),
+        on_retry=load_env
+    )
+    set_config(config)  # The other modules use this copy instead of reading the file again
+except FileNotFoundError:
+    logger.error(f"❌ Config file not found: {config_path}")
+    logger.error("💡 Copy config.example.yaml to config.yaml or set CONFIG_PATH")
+    exit(1)
+except ConfigError as e:
+    if "DISCORD_TOKEN" not in str(e):
+        logger.error(f"❌ Invalid config: {e}")
+        exit(1)
+    logger.error("❌ DISCORD_TOKEN is not set!")
+    logger.error("💡 Create a .env file with: DISCORD_TOKEN=your_bot_token_here")
+    exit(1)
+
+from src.tree import AlastorCommandTree, prune_rate_limiters, record_command_latency
+from src.http_client import close_session
+from src.health_server import set_ready, start_health_server
+from src.commands.radio import RadioCogEnhanced, guild_settings, stop_all_stations, restore_stations, auto_join_stations, load_owner_ids, update_presence
+from src.command_permissions import sync_dj_permissions
 from src.commands.info import InfoCog
 from src.commands.donate import DonateCog
 from src.commands.help import HelpCog
-
-# Load configuration (via ENV variable CONFIG_PATH, default: config.yaml)
-config_path = os.getenv("CONFIG_PATH", "config.yaml")
-config = load_config(config_path)
+from src.commands.admin import AdminCog
+from src.commands.setup import SetupCog
+from src.commands.legacy import LegacyCog
 
 BOT_PREFIX = config["bot"]["prefix"]
 DISCORD_TOKEN = os.getenv("DISCORD_TOKEN")
@@ -50,29 +88,47 @@ logger.info("🎵 Starting Alastor - The Radio Daemon...")
 logger.info(f"📁 Config loaded from: {config_path}")
 logger.info(f"🔧 Bot prefix: {BOT_PREFIX}")
 
-if not DISCORD_TOKEN:
-    logger.error("❌ DISCORD_TOKEN is not set!")
-    logger.error("💡 Create a .env file with: DISCORD_TOKEN=your_bot_token_here")
-    exit(1)
-
 logger.info("🔐 Discord token loaded successfully")
 
+# Optional OpenTelemetry export (ALASTOR_OTEL_ENDPOINT)
+init_telemetry()
+
 intents = discord.Intents.default()
-intents.message_content = True
+intents.message_content = config["bot"]["legacy_commands"]  # Privileged; only the prefix commands read messages
 intents.voice_states = True
-intents.guilds = True
+intents.guilds = True  # Fills the guild cache used for listener counts and uptime displays
 
-bot = commands.Bot(
-    command_prefix=BOT_PREFIX,
-    intents=intents,
-    description=config["bot"]["description"]
-)
+# Sharding: without a shard count the bot runs as a single shard
+BOT_SHARDS = config["bot"]["shards"]
+if BOT_SHARDS:
+    logger.info(f"🧩 Running with {BOT_SHARDS} shards")
+    bot = commands.AutoShardedBot(
+        command_prefix=BOT_PREFIX,
+        intents=intents,
+        description=config["bot"]["description"],
+        shard_count=BOT_SHARDS,
+        max_messages=None,  # The bot never reads cached messages
+        shard_ids=[config["bot"]["shard_id"]] if config["bot"]["shard_id"] is not None else None,
+        owner_id=config["bot"]["owner_id"],
+        tree_cls=AlastorCommandTree
+    )
+else:
+    bot = commands.Bot(
+        command_prefix=BOT_PREFIX,
+        intents=intents,
+        description=config["bot"]["description"],
+        max_messages=None,  # The bot never reads cached messages
+        owner_id=config["bot"]["owner_id"],
+        tree_cls=AlastorCommandTree
+    )
 
 @bot.event
 async def on_ready():
     logger.info("=" * 50)
     logger.info(f"🤖 Logged in as: {bot.user} (ID: {bot.user.id})")
     logger.info(f"🌐 Connected to {len(bot.guilds)} servers")
+    if bot.shard_count:
+        logger.info(f"🧩 Shards: {bot.shard_count}")
     
     # List servers
     for guild in bot.guilds:
@@ -84,13 +140,29 @@ async def on_ready():
     except Exception as e:
         logger.error(f"❌ Error syncing slash commands: {e}")
     
-    await bot.change_presence(activity=discord.Game(name="Radio"))
+    try:
+        await load_owner_ids(bot)
+    except discord.HTTPException as e:
+        logger.error(f"❌ Could not look up the bot owner: {e}")
+
+    await sync_dj_permissions(bot, guild_settings)
+    await update_presence(bot)
     logger.info("🎵 Bot is ready and online!")
     logger.info("=" * 50)
+    set_ready()
+
+    # Resume the streams that were playing before the last shutdown
+    if config["bot"]["restore_on_restart"]:
+        await restore_stations(bot)
+    await auto_join_stations(bot)
+
+@bot.event
+async def on_app_command_completion(interaction: discord.Interaction, command):
+    record_command_latency(interaction)
 
 async def setup():
     logger.info("🔧 Loading bot extensions...")
-    await bot.add_cog(RadioCog(bot))
+    await bot.add_cog(RadioCogEnhanced(bot))
     logger.info("   ✅ RadioCog loaded")
     await bot.add_cog(InfoCog(bot))
     logger.info("   ✅ InfoCog loaded")
@@ -98,11 +170,46 @@ async def setup():
     logger.info("   ✅ DonateCog loaded")
     await bot.add_cog(HelpCog(bot))
     logger.info("   ✅ HelpCog loaded")
+    await bot.add_cog(AdminCog(bot))
+    logger.info("   ✅ AdminCog loaded")
+    await bot.add_cog(SetupCog(bot))
+    logger.info("   ✅ SetupCog loaded")
+    if config["bot"]["legacy_commands"]:
+        await bot.add_cog(LegacyCog(bot))
+        logger.info(f"   ✅ LegacyCog loaded (prefix: {BOT_PREFIX})")
+    prune_rate_limiters.start()
+
+async def shutdown():
+    """Stop all streams (remembering them for restore) and close all shards"""
+    logger.info("🛑 Received SIGTERM, shutting down...")
+    set_ready(False)
+    try:
+        await stop_all_stations(bot)
+    except Exception as e:
+        logger.error(f"❌ Error stopping stations during shutdown: {e}")
+    await close_session()
+    await bot.close()
 
 async def main():
     async with bot:
         await setup()
-        await bot.start(DISCORD_TOKEN)
+        try:
+            asyncio.get_running_loop().add_signal_handler(signal.SIGTERM, lambda: asyncio.create_task(shutdown()))
+        except NotImplementedError:
+            # Signal handlers are not available on Windows
+            logger.warning("⚠️  SIGTERM handler not supported on this platform")
+        # Probe endpoints for container orchestrators (health_port: 0 disables them)
+        health_runner = None
+        if config["bot"]["health_port"]:
+            try:
+                health_runner = await start_health_server(bot, config["bot"]["health_port"])
+            except OSError as e:
+                logger.error(f"❌ Could not start health endpoints on port {config['bot']['health_port']}: {e}")
+        try:
+            await bot.start(DISCORD_TOKEN)
+        finally:
+            if health_runner:
+                await health_runner.cleanup()
 
 if __name__ == '__main__':
     asyncio.run(main())
\ No newline at end of file
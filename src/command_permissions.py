@@ -0,0 +1,84 @@
+import logging
+from typing import Dict, Optional
+import sys
+from pathlib import Path
+
+# Add the parent directory to sys.path to allow imports
+sys.path.insert(0, str(Path(__file__).parent.parent))
+
+from src.config import get_config
+from src.http_client import get_session
+
+logger = logging.getLogger(__name__)
+
+# Configuration loaded by bot.py
+config = get_config()
+USE_COMMAND_PERMISSIONS = config["bot"]["use_command_permissions"]
+# Discord doesn't accept bot tokens for this endpoint; it needs the OAuth2 bearer token of a
+# server admin with the applications.commands.permissions.update scope
+PERMISSIONS_TOKEN = config["bot"]["command_permissions_token"]
+API_BASE = "https://discord.com/api/v10"
+ROLE_PERMISSION = 1  # Permission type for a role ID
+
+_radio_command_id: Optional[int] = None
+
+def build_command_permissions(guild_id: int, dj_role_id: Optional[int]) -> Dict:
+    """
+    Permissions that show a command only to the DJ role: the role is allowed and @everyone
+    (whose role ID is the guild ID) is denied. Without a DJ role the list is empty,
+    which gives everyone the command back.
+    """
+    if dj_role_id is None:
+        return {"permissions": []}
+    return {"permissions": [
+        {"id": str(dj_role_id), "type": ROLE_PERMISSION, "permission": True},
+        {"id": str(guild_id), "type": ROLE_PERMISSION, "permission": False},
+    ]}
+
+async def get_radio_command_id(client) -> Optional[int]:
+    """ID of the global /radio command, looked up once"""
+    global _radio_command_id
+    if _radio_command_id is None:
+        commands = await client.tree.fetch_commands()
+        _radio_command_id = next((command.id for command in commands if command.name == "radio"), None)
+    return _radio_command_id
+
+async def apply_dj_permissions(client, guild_id: int, dj_role_id: Optional[int]) -> bool:
+    """
+    Show /radio only to the DJ role of a guild, or to everyone again without one.
+    Discord applies permissions to whole commands, so this covers all /radio subcommands,
+    not only play and stop. Does nothing unless use_command_permissions is on; errors are
+    logged only. Returns whether the permissions were updated.
+    """
+    if not USE_COMMAND_PERMISSIONS:
+        return False
+    if not PERMISSIONS_TOKEN:
+        logger.warning("⚠️ use_command_permissions is on, but command_permissions_token is not set")
+        return False
+
+    try:
+        command_id = await get_radio_command_id(client)
+        if command_id is None:
+            logger.warning("⚠️ /radio is not registered yet, can't set its permissions")
+            return False
+        url = f"{API_BASE}/applications/{client.application_id}/guilds/{guild_id}/commands/{command_id}/permissions"
+        headers = {"Authorization": f"Bearer {PERMISSIONS_TOKEN}"}
+        async with get_session().put(url, json=build_command_permissions(guild_id, dj_role_id), headers=headers) as response:
+            if response.status >= 400:
+                logger.warning(f"⚠️ Discord refused the /radio permissions for guild {guild_id}: HTTP {response.status}")
+                return False
+    except Exception as e:
+        logger.error(f"❌ Failed to update the /radio permissions for guild {guild_id}: {e}")
+        return False
+
+    logger.info(f"🔐 /radio is now shown to {'the DJ role only' if dj_role_id else 'everyone'} in guild {guild_id}")
+    return True
+
+async def sync_dj_permissions(client, guild_settings: Dict[int, Dict]):
+    """Apply the DJ role of every guild that has one, e.g. on startup"""
+    if not USE_COMMAND_PERMISSIONS:
+        return
+    for guild in client.guilds:
+        dj_role_id = guild_settings.get(guild.id, {}).get("dj_role")
+        if dj_role_id:
+            await apply_dj_permissions(client, guild.id, dj_role_id)
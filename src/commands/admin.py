@@ -0,0 +1,894 @@
+import io
+import os
+import asyncio
+import time
+import logging
+import discord
+from discord.ext import commands
+from discord import app_commands, ui, Interaction, Embed
+from typing import List, Optional
+import sys
+from pathlib import Path
+
+# Add the parent directory to sys.path to allow imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from src.config import is_valid_url, load_config, read_config, check_tls, validate_config, diff_configs
+from src.errors import ConfigError, PermissionDeniedError, collect_errors
+from src.utils import format_duration
+from src.commands.radio import (
+    RADIOS,
+    config,
+    current_radios,
+    server_stations,
+    disabled_stations,
+    get_all_active_streams,
+    filter_by_shard,
+    get_available_stations,
+    guild_settings,
+    save_state,
+    stop_station,
+    stop_all_stations,
+    save_config_stations,
+    get_config_fields,
+    add_global_station,
+    export_stations_m3u,
+    import_stations_from_m3u,
+    MAX_IMPORT_STATIONS,
+    safe_send_message,
+    is_safe_url,
+    check_stream_health,
+    get_health_emoji,
+    is_admin,
+)
+from src.stats import stats_tracker
+from src.latency import command_latencies, health_check_latencies
+from src.http_client import get_session
+
+logger = logging.getLogger(__name__)
+
+async def get_global_station_autocomplete(interaction: Interaction, current: str) -> List[app_commands.Choice[str]]:
+    """Get autocomplete choices for global station names"""
+    current_lower = current.lower()
+    return [
+        app_commands.Choice(name=name[:100], value=name)
+        for name in RADIOS.keys()
+        if current_lower in name.lower()
+    ][:25]  # Discord limit
+
+async def get_enabled_station_autocomplete(interaction: Interaction, current: str) -> List[app_commands.Choice[str]]:
+    """Get autocomplete choices for stations of this server that can be disabled"""
+    current_lower = current.lower()
+    return [
+        app_commands.Choice(name=name[:100], value=name)
+        for name in get_available_stations(interaction.guild_id)
+        if name not in disabled_stations and current_lower in name.lower()
+    ][:25]
+
+async def get_disabled_station_autocomplete(interaction: Interaction, current: str) -> List[app_commands.Choice[str]]:
+    """Get autocomplete choices for disabled stations"""
+    current_lower = current.lower()
+    return [
+        app_commands.Choice(name=name[:100], value=name)
+        for name in sorted(disabled_stations)
+        if current_lower in name.lower()
+    ][:25]
+
+GUILD_PAGE_SIZE = 10
+
+def build_guild_list_embed(bot: commands.Bot, page: int) -> Embed:
+    """One page of active streams with station, listeners and uptime"""
+    streams = get_all_active_streams()
+    total_pages = max((len(streams) + GUILD_PAGE_SIZE - 1) // GUILD_PAGE_SIZE, 1)
+
+    lines = []
+    for guild_id, radio_data in streams[page * GUILD_PAGE_SIZE:(page + 1) * GUILD_PAGE_SIZE]:
+        guild = bot.get_guild(guild_id)
+        voice_client = radio_data.get("voice_client")
+        channel = getattr(voice_client, "channel", None)
+        listeners = len([member for member in channel.members if not member.bot]) if channel else 0
+        uptime = format_duration(time.time() - radio_data.get("start_time", time.time()))
+        lines.append(
+            f"**{guild.name if guild else 'Unknown server'}** (`{guild_id}`)\n"
+            f"▶️ {radio_data['name']} • 👥 {listeners} • ⏱️ {uptime}"
+        )
+
+    embed = Embed(
+        title=f"🌐 Active Streams ({len(streams)} of {len(bot.guilds)} servers)",
+        description="\n\n".join(lines) or "Nothing is playing on any server.",
+        color=discord.Color.blue()
+    )
+    embed.set_footer(text=f"Alastor - The Radio Daemon • Page {page + 1} of {total_pages}")
+    return embed
+
+class GuildListView(ui.View):
+    def __init__(self, bot: commands.Bot, page: int = 0):
+        super().__init__(timeout=120)
+        self.bot = bot
+        self.page = page
+        total_pages = (len(get_all_active_streams()) + GUILD_PAGE_SIZE - 1) // GUILD_PAGE_SIZE
+        self.previous_page.disabled = page == 0
+        self.next_page.disabled = page >= total_pages - 1
+
+    @ui.button(label="◀️ Previous", style=discord.ButtonStyle.secondary)
+    async def previous_page(self, interaction: Interaction, button: ui.Button):
+        await interaction.response.edit_message(
+            embed=build_guild_list_embed(self.bot, self.page - 1),
+            view=GuildListView(self.bot, self.page - 1)
+        )
+
+    @ui.button(label="Next ▶️", style=discord.ButtonStyle.secondary)
+    async def next_page(self, interaction: Interaction, button: ui.Button):
+        await interaction.response.edit_message(
+            embed=build_guild_list_embed(self.bot, self.page + 1),
+            view=GuildListView(self.bot, self.page + 1)
+        )
+
+def check_new_station(name: str, url: str) -> Optional[Embed]:
+    """Error embed if a new global station's name is taken or its URL is unsafe, otherwise None"""
+    if not name:
+        return Embed(title="❌ Missing Name", description="The station needs a name.", color=discord.Color.red())
+    if any(existing.lower() == name.lower() for existing in RADIOS):
+        return Embed(
+            title="⚠️ Station Exists",
+            description=f"A global station named **{name}** already exists.\n\nChoose a different name.",
+            color=discord.Color.yellow()
+        )
+    is_safe, safety_message = is_safe_url(url)
+    if not is_valid_url(url) or not is_safe:
+        return Embed(
+            title="❌ Invalid URL",
+            description=f"{safety_message if not is_safe else 'URL could not be parsed.'}\n\nFor security, only safe streaming URLs are allowed.",
+            color=discord.Color.red()
+        )
+    return None
+
+def build_station_added_embed(interaction: Interaction, name: str, url: str, description: str = "",
+                              bitrate: Optional[int] = None, format: Optional[str] = None) -> Embed:
+    """Confirmation for /admin station add and the station wizard"""
+    embed = Embed(
+        title="✅ Global Station Added",
+        description=f"**{name}** is now available on all servers.",
+        color=discord.Color.green()
+    )
+    embed.add_field(name="URL", value=url[:100] + ("..." if len(url) > 100 else ""), inline=False)
+    if description:
+        embed.add_field(name="Description", value=description, inline=False)
+    if bitrate:
+        embed.add_field(name="Bitrate", value=f"{bitrate} kbps", inline=True)
+    if format:
+        embed.add_field(name="Format", value=format, inline=True)
+    embed.add_field(name="Added by", value=interaction.user.mention, inline=True)
+    embed.set_footer(text="Alastor - The Radio Daemon")
+    return embed
+
+class StationWizardModal(ui.Modal, title="Add Station (1/2)"):
+    """Step 1 of /admin station import-wizard: name and stream URL, checked before asking for details"""
+    name = ui.TextInput(label="Name", placeholder="e.g. Jazz FM", max_length=100)
+    url = ui.TextInput(label="Stream URL", placeholder="https://example.com/stream.mp3 (or .m3u, .pls)", max_length=500)
+
+    def __init__(self):
+        super().__init__(custom_id="station_wizard_step1")
+
+    async def on_submit(self, interaction: Interaction):
+        name, url = self.name.value.strip(), self.url.value.strip()
+        error_embed = check_new_station(name, url)
+        if error_embed:
+            await safe_send_message(interaction, embed=error_embed, ephemeral=True)
+            return
+
+        loading_embed = Embed(
+            title="🔄 Testing Station...",
+            description=f"Running health check for **{name}**...",
+            color=discord.Color.orange()
+        )
+        await safe_send_message(interaction, embed=loading_embed, ephemeral=True)
+
+        is_healthy, health_message = await check_stream_health(url, name)
+        if not is_healthy:
+            embed = Embed(
+                title="❌ Health Check Failed",
+                description=f"**{name}** could not be reached, so it was not added.\n\nError: {health_message}",
+                color=discord.Color.red()
+            )
+            await interaction.edit_original_response(embed=embed)
+            return
+
+        embed = Embed(
+            title="✅ Stream Reachable",
+            description=f"**{name}** answered the health check.\n\nContinue to add a description, bitrate and format.",
+            color=discord.Color.green()
+        )
+        embed.set_footer(text="Alastor - The Radio Daemon • Step 1 of 2")
+        await interaction.edit_original_response(embed=embed, view=StationWizardView(interaction.user.id, name, url))
+
+class StationWizardView(ui.View):
+    """A modal can't be answered with another modal, so step 2 opens from a button"""
+    def __init__(self, user_id: int, name: str, url: str):
+        super().__init__(timeout=300)
+        self.user_id = user_id
+        self.name = name
+        self.url = url
+
+    async def interaction_check(self, interaction: Interaction) -> bool:
+        return interaction.user.id == self.user_id
+
+    @ui.button(label="Continue", style=discord.ButtonStyle.primary, emoji="➡️")
+    async def continue_button(self, interaction: Interaction, button: ui.Button):
+        await interaction.response.send_modal(StationWizardDetailsModal(self.name, self.url))
+
+class StationWizardDetailsModal(ui.Modal, title="Add Station (2/2)"):
+    """Step 2 of /admin station import-wizard: optional details, then the station is added"""
+    description = ui.TextInput(label="Description", style=discord.TextStyle.paragraph, required=False, max_length=300)
+    bitrate = ui.TextInput(label="Bitrate (kbps)", placeholder="e.g. 128", required=False, max_length=5)
+    format = ui.TextInput(label="Format", placeholder="e.g. MP3, AAC", required=False, max_length=20)
+
+    def __init__(self, name: str, url: str):
+        super().__init__(custom_id="station_wizard_step2")
+        self.station_name = name
+        self.station_url = url
+
+    async def on_submit(self, interaction: Interaction):
+        bitrate = self.bitrate.value.strip()
+        if bitrate and (not bitrate.isdigit() or int(bitrate) < 1):
+            embed = Embed(
+                title="❌ Invalid Bitrate",
+                description=f"`{bitrate}` is not a bitrate in kbps.\n\nPress **Continue** again to correct it.",
+                color=discord.Color.red()
+            )
+            await safe_send_message(interaction, embed=embed, ephemeral=True)
+            return
+        # Someone may have added a station with this name while the wizard was open
+        error_embed = check_new_station(self.station_name, self.station_url)
+        if error_embed:
+            await safe_send_message(interaction, embed=error_embed, ephemeral=True)
+            return
+
+        description, format = self.description.value.strip(), self.format.value.strip()
+        add_global_station(self.station_name, self.station_url, interaction.user.id, description=description,
+                           bitrate=int(bitrate) if bitrate else None, format=format)
+        logger.info(f"➕ Admin {interaction.user.display_name} added global station '{self.station_name}' with the wizard: {self.station_url[:50]}...")
+        embed = build_station_added_embed(interaction, self.station_name, self.station_url, description,
+                                          int(bitrate) if bitrate else None, format)
+        # Replace the step 1 message, so the wizard can't add the station twice
+        await interaction.response.edit_message(embed=embed, view=None)
+
+class AdminCog(commands.Cog):
+    def __init__(self, bot: commands.Bot):
+        self.bot = bot
+
+    # Bot-wide administration; hidden from members without Administrator permission
+    admin = app_commands.Group(
+        name="admin",
+        description="Bot administration commands",
+        default_permissions=discord.Permissions(administrator=True),
+        guild_only=True
+    )
+    station = app_commands.Group(name="station", description="Manage global radio stations", parent=admin)
+    blacklist = app_commands.Group(name="blacklist", description="Block users from controlling the radio", parent=admin)
+    guild = app_commands.Group(name="guild", description="Inspect and control the servers the bot is in (bot owner only)", parent=admin)
+    bot_config = app_commands.Group(name="config", description="Reload and inspect the bot configuration (bot owner only)", parent=admin)
+    bot_stats = app_commands.Group(name="stats", description="Inspect bot performance (bot owner only)", parent=admin)
+    shard = app_commands.Group(name="shard", description="Inspect the gateway shards (bot owner only)", parent=admin)
+
+    async def check_owner(self, interaction: Interaction, action: str):
+        """Commands affecting other servers are limited to the bot owner"""
+        if not await self.bot.is_owner(interaction.user):
+            raise PermissionDeniedError(interaction.user.id, action)
+
+    @station.command(name="add", description="Add a global radio station (Admin only)")
+    @app_commands.describe(
+        name="Station name",
+        url="Stream URL (supports .m3u, .m3u8, .pls playlists)",
+        description="Optional description",
+        bitrate="Optional bitrate in kbps",
+        format="Optional stream format (e.g. MP3, AAC)"
+    )
+    async def station_add(self, interaction: Interaction, name: str, url: str, description: str = "",
+                          bitrate: int = None, format: str = None):
+        if not is_admin(interaction.user):
+            embed = Embed(
+                title="❌ Permission Denied",
+                description="Only server administrators can add global stations.",
+                color=discord.Color.red()
+            )
+            await safe_send_message(interaction, embed=embed, ephemeral=True)
+            return
+
+        name = name.strip()
+        error_embed = check_new_station(name, url)
+        if error_embed:
+            await safe_send_message(interaction, embed=error_embed, ephemeral=True)
+            return
+
+        loading_embed = Embed(
+            title="🔄 Testing Station...",
+            description=f"Running health check for **{name}**...",
+            color=discord.Color.orange()
+        )
+        await safe_send_message(interaction, embed=loading_embed, ephemeral=True)
+
+        is_healthy, health_message = await check_stream_health(url, name)
+        if not is_healthy:
+            embed = Embed(
+                title="❌ Health Check Failed",
+                description=f"**{name}** could not be reached, so it was not added.\n\nError: {health_message}",
+                color=discord.Color.red()
+            )
+            await interaction.edit_original_response(embed=embed)
+            return
+
+        add_global_station(name, url, interaction.user.id, description=description, bitrate=bitrate, format=format)
+
+        logger.info(f"➕ Admin {interaction.user.display_name} added global station '{name}': {url[:50]}...")
+        await interaction.edit_original_response(embed=build_station_added_embed(interaction, name, url, description, bitrate, format))
+
+    @station.command(name="import-wizard", description="Add a global radio station step by step (Admin only)")
+    async def station_import_wizard(self, interaction: Interaction):
+        if not is_admin(interaction.user):
+            embed = Embed(
+                title="❌ Permission Denied",
+                description="Only server administrators can add global stations.",
+                color=discord.Color.red()
+            )
+            await safe_send_message(interaction, embed=embed, ephemeral=True)
+            return
+        await interaction.response.send_modal(StationWizardModal())
+
+    @station.command(name="remove", description="Remove a global radio station (Admin only)")
+    @app_commands.describe(
+        name="Station name to remove",
+        force="Stop the station on every server where it is currently playing"
+    )
+    @app_commands.autocomplete(name=get_global_station_autocomplete)
+    async def station_remove(self, interaction: Interaction, name: str, force: bool = False):
+        if not is_admin(interaction.user):
+            embed = Embed(
+                title="❌ Permission Denied",
+                description="Only server administrators can remove global stations.",
+                color=discord.Color.red()
+            )
+            await safe_send_message(interaction, embed=embed, ephemeral=True)
+            return
+
+        if name not in RADIOS:
+            embed = Embed(
+                title="❌ Station Not Found",
+                description=f"Global station **{name}** does not exist.",
+                color=discord.Color.red()
+            )
+            await safe_send_message(interaction, embed=embed, ephemeral=True)
+            return
+
+        # Check whether any server is currently playing this station
+        affected_guilds = [guild_id for guild_id, data in current_radios.items() if data["name"] == name]
+        if affected_guilds and not force:
+            guild_list = "\n".join(
+                f"• {self.bot.get_guild(guild_id).name if self.bot.get_guild(guild_id) else guild_id}"
+                for guild_id in affected_guilds
+            )
+            embed = Embed(
+                title="⚠️ Station In Use",
+                description=f"**{name}** is currently playing on {len(affected_guilds)} server(s):\n{guild_list}\n\nRun the command again with `force: True` to stop it everywhere and remove it.",
+                color=discord.Color.yellow()
+            )
+            await safe_send_message(interaction, embed=embed, ephemeral=True)
+            return
+
+        _, stop_error = collect_errors(await asyncio.gather(
+            *(stop_station(self.bot, guild_id) for guild_id in affected_guilds),
+            return_exceptions=True
+        ))
+        if affected_guilds:
+            logger.info(f"⏹️ Force-stopped '{name}' in {len(affected_guilds)} guild(s) before removal")
+        if stop_error:
+            logger.error(f"❌ Stopping '{name}' failed on some servers:\n{stop_error}")
+
+        del RADIOS[name]
+        save_state()
+        save_config_stations({name: None})
+
+        logger.info(f"➖ Admin {interaction.user.display_name} removed global station '{name}'")
+
+        embed = Embed(
+            title="✅ Global Station Removed",
+            description=f"**{name}** has been removed from all servers.",
+            color=discord.Color.green()
+        )
+        if affected_guilds:
+            embed.add_field(name="Stopped on", value=f"{len(affected_guilds)} server(s)", inline=True)
+        if stop_error:
+            embed.add_field(name="⚠️ Errors while stopping", value=stop_error.description[:1024], inline=False)
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await safe_send_message(interaction, embed=embed, ephemeral=True)
+
+    @station.command(name="disable", description="Prevent a station from being played (Admin only)")
+    @app_commands.describe(name="Station name to disable")
+    @app_commands.autocomplete(name=get_enabled_station_autocomplete)
+    async def station_disable(self, interaction: Interaction, name: str):
+        if not is_admin(interaction.user):
+            embed = Embed(
+                title="❌ Permission Denied",
+                description="Only server administrators can disable stations.",
+                color=discord.Color.red()
+            )
+            await safe_send_message(interaction, embed=embed, ephemeral=True)
+            return
+
+        if name not in get_available_stations(interaction.guild_id):
+            embed = Embed(
+                title="❌ Station Not Found",
+                description=f"Station **{name}** does not exist.",
+                color=discord.Color.red()
+            )
+            await safe_send_message(interaction, embed=embed, ephemeral=True)
+            return
+
+        disabled_stations.add(name)
+        save_state()
+
+        logger.info(f"⛔ Admin {interaction.user.display_name} disabled station '{name}'")
+
+        embed = Embed(
+            title="⛔ Station Disabled",
+            description=f"**{name}** can no longer be played until it is enabled again.\n\nStreams that are already playing keep running.",
+            color=discord.Color.green()
+        )
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await safe_send_message(interaction, embed=embed, ephemeral=True)
+
+    @station.command(name="enable", description="Allow a disabled station to be played again (Admin only)")
+    @app_commands.describe(name="Station name to enable")
+    @app_commands.autocomplete(name=get_disabled_station_autocomplete)
+    async def station_enable(self, interaction: Interaction, name: str):
+        if not is_admin(interaction.user):
+            embed = Embed(
+                title="❌ Permission Denied",
+                description="Only server administrators can enable stations.",
+                color=discord.Color.red()
+            )
+            await safe_send_message(interaction, embed=embed, ephemeral=True)
+            return
+
+        if name not in disabled_stations:
+            embed = Embed(
+                title="❌ Not Disabled",
+                description=f"Station **{name}** is not disabled.",
+                color=discord.Color.red()
+            )
+            await safe_send_message(interaction, embed=embed, ephemeral=True)
+            return
+
+        disabled_stations.discard(name)
+        save_state()
+
+        logger.info(f"✅ Admin {interaction.user.display_name} enabled station '{name}'")
+
+        embed = Embed(
+            title="✅ Station Enabled",
+            description=f"**{name}** can be played again.",
+            color=discord.Color.green()
+        )
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await safe_send_message(interaction, embed=embed, ephemeral=True)
+
+    @station.command(name="list", description="List all global radio stations (Admin only)")
+    async def station_list(self, interaction: Interaction):
+        if not is_admin(interaction.user):
+            embed = Embed(
+                title="❌ Permission Denied",
+                description="Only server administrators can list global stations.",
+                color=discord.Color.red()
+            )
+            await safe_send_message(interaction, embed=embed, ephemeral=True)
+            return
+
+        lines = [f"{get_health_emoji(station['url'])} **{name}** — {station['url'][:60]}" for name, station in RADIOS.items()]
+        description = "\n".join(lines) or "No global stations configured."
+        if len(description) > 4000:
+            description = description[:4000].rsplit("\n", 1)[0] + "\n…"
+        embed = Embed(
+            title=f"📻 Global Stations ({len(RADIOS)})",
+            description=description,
+            color=discord.Color.blue()
+        )
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await safe_send_message(interaction, embed=embed, ephemeral=True)
+
+    @station.command(name="export", description="Download the global stations as an M3U playlist (Admin only)")
+    async def station_export(self, interaction: Interaction):
+        if not is_admin(interaction.user):
+            embed = Embed(
+                title="❌ Permission Denied",
+                description="Only server administrators can export global stations.",
+                color=discord.Color.red()
+            )
+            await safe_send_message(interaction, embed=embed, ephemeral=True)
+            return
+
+        playlist_file = discord.File(io.BytesIO(export_stations_m3u().encode("utf-8")), filename="stations.m3u")
+        logger.info(f"📤 Admin {interaction.user.display_name} exported {len(RADIOS)} global stations")
+
+        embed = Embed(
+            title="📤 Stations Exported",
+            description=f"**{len(RADIOS)}** global station{'s' if len(RADIOS) != 1 else ''} as an M3U playlist.",
+            color=discord.Color.green()
+        )
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await interaction.response.send_message(embed=embed, file=playlist_file, ephemeral=True)
+
+    @station.command(name="import", description="Add global stations from an M3U playlist file (Admin only)")
+    @app_commands.describe(file=f"Extended M3U file, e.g. from /admin station export (max. {MAX_IMPORT_STATIONS} stations)")
+    async def station_import(self, interaction: Interaction, file: discord.Attachment):
+        if not is_admin(interaction.user):
+            embed = Embed(
+                title="❌ Permission Denied",
+                description="Only server administrators can import global stations.",
+                color=discord.Color.red()
+            )
+            await safe_send_message(interaction, embed=embed, ephemeral=True)
+            return
+
+        await interaction.response.defer(ephemeral=True, thinking=True)
+        error = None
+        if file.size > 1024 * 1024:
+            error = "The file is too large (max. 1 MB)."
+        else:
+            try:
+                async with get_session().get(file.url) as response:
+                    response.raise_for_status()
+                    content = await response.text(errors="replace")
+                imported, skipped = import_stations_from_m3u(content, interaction.user.id)
+            except ValueError as e:
+                error = f"The file is not a usable M3U playlist: {e}"
+            except Exception as e:
+                logger.error(f"❌ Downloading station import {file.filename} failed: {e}")
+                error = "The file could not be downloaded. Please try again."
+        if error:
+            embed = Embed(title="❌ Import Failed", description=error, color=discord.Color.red())
+            embed.set_footer(text="Alastor - The Radio Daemon")
+            await safe_send_message(interaction, embed=embed, ephemeral=True)
+            return
+
+        if imported:
+            save_state()
+            save_config_stations({name: get_config_fields(RADIOS[name]) for name in imported})
+        logger.info(f"📥 Admin {interaction.user.display_name} imported {len(imported)} global stations from {file.filename} ({len(skipped)} skipped)")
+
+        embed = Embed(
+            title="📥 Stations Imported",
+            description=f"**{len(imported)}** station{'s' if len(imported) != 1 else ''} imported, **{len(skipped)}** skipped.",
+            color=discord.Color.green() if imported else discord.Color.yellow()
+        )
+        if imported:
+            embed.add_field(name="Imported", value=", ".join(imported)[:1024], inline=False)
+        if skipped:
+            embed.add_field(name="Skipped (duplicate, unnamed, unsafe or over the limit)", value=", ".join(skipped)[:1024], inline=False)
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await safe_send_message(interaction, embed=embed, ephemeral=True)
+
+    @admin.command(name="reset-stats", description="Reset playback statistics for this server (Admin only)")
+    async def reset_stats(self, interaction: Interaction):
+        if not is_admin(interaction.user):
+            embed = Embed(
+                title="❌ Permission Denied",
+                description="Only server administrators can reset statistics.",
+                color=discord.Color.red()
+            )
+            await safe_send_message(interaction, embed=embed, ephemeral=True)
+            return
+
+        stats_tracker.reset(interaction.guild_id)
+        save_state()
+        logger.info(f"🧹 Admin {interaction.user.display_name} reset statistics in {interaction.guild.name}")
+
+        embed = Embed(
+            title="✅ Statistics Reset",
+            description="Playback statistics for this server have been cleared.",
+            color=discord.Color.green()
+        )
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await safe_send_message(interaction, embed=embed, ephemeral=True)
+
+    @blacklist.command(name="add", description="Block a user from using /radio on this server (Admin only)")
+    @app_commands.describe(user="User to block")
+    async def blacklist_add(self, interaction: Interaction, user: discord.Member):
+        if not is_admin(interaction.user):
+            embed = Embed(
+                title="❌ Permission Denied",
+                description="Only server administrators can manage the blacklist.",
+                color=discord.Color.red()
+            )
+            await safe_send_message(interaction, embed=embed, ephemeral=True)
+            return
+
+        blacklisted = guild_settings.setdefault(interaction.guild_id, {}).setdefault("blacklisted_users", [])
+        if user.id in blacklisted:
+            embed = Embed(
+                title="⚠️ Already Blocked",
+                description=f"{user.mention} is already blocked on this server.",
+                color=discord.Color.yellow()
+            )
+            await safe_send_message(interaction, embed=embed, ephemeral=True)
+            return
+
+        blacklisted.append(user.id)
+        save_state()
+        logger.info(f"🚫 Admin {interaction.user.display_name} blocked {user} in {interaction.guild.name}")
+
+        embed = Embed(
+            title="🚫 User Blocked",
+            description=f"{user.mention} can no longer use `/radio` commands on this server.",
+            color=discord.Color.green()
+        )
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await safe_send_message(interaction, embed=embed, ephemeral=True)
+
+    @blacklist.command(name="remove", description="Unblock a user on this server (Admin only)")
+    @app_commands.describe(user="User to unblock")
+    async def blacklist_remove(self, interaction: Interaction, user: discord.User):
+        if not is_admin(interaction.user):
+            embed = Embed(
+                title="❌ Permission Denied",
+                description="Only server administrators can manage the blacklist.",
+                color=discord.Color.red()
+            )
+            await safe_send_message(interaction, embed=embed, ephemeral=True)
+            return
+
+        blacklisted = guild_settings.get(interaction.guild_id, {}).get("blacklisted_users", [])
+        if user.id not in blacklisted:
+            embed = Embed(
+                title="❌ Not Blocked",
+                description=f"{user.mention} is not blocked on this server.",
+                color=discord.Color.red()
+            )
+            await safe_send_message(interaction, embed=embed, ephemeral=True)
+            return
+
+        blacklisted.remove(user.id)
+        save_state()
+        logger.info(f"✅ Admin {interaction.user.display_name} unblocked {user} in {interaction.guild.name}")
+
+        embed = Embed(
+            title="✅ User Unblocked",
+            description=f"{user.mention} can use `/radio` commands again.",
+            color=discord.Color.green()
+        )
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await safe_send_message(interaction, embed=embed, ephemeral=True)
+
+    @blacklist.command(name="list", description="Show blocked users on this server (Admin only)")
+    async def blacklist_list(self, interaction: Interaction):
+        if not is_admin(interaction.user):
+            embed = Embed(
+                title="❌ Permission Denied",
+                description="Only server administrators can view the blacklist.",
+                color=discord.Color.red()
+            )
+            await safe_send_message(interaction, embed=embed, ephemeral=True)
+            return
+
+        blacklisted = guild_settings.get(interaction.guild_id, {}).get("blacklisted_users", [])
+        embed = Embed(
+            title="🚫 Blocked Users",
+            description="\n".join(f"• <@{user_id}>" for user_id in blacklisted) if blacklisted else "No users are blocked on this server.",
+            color=discord.Color.blue()
+        )
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await safe_send_message(interaction, embed=embed, ephemeral=True)
+
+    @guild.command(name="list", description="List the servers with an active stream (bot owner only)")
+    async def guild_list(self, interaction: Interaction):
+        await self.check_owner(interaction, "list the bot's servers")
+
+        await safe_send_message(interaction, embed=build_guild_list_embed(self.bot, 0), view=GuildListView(self.bot), ephemeral=True)
+
+    @guild.command(name="info", description="Show details about a server (bot owner only)")
+    @app_commands.describe(guild_id="Server ID (defaults to this server)")
+    async def guild_info(self, interaction: Interaction, guild_id: str = None):
+        await self.check_owner(interaction, "inspect other servers")
+
+        if not guild_id:
+            guild = interaction.guild
+        else:
+            guild = self.bot.get_guild(int(guild_id)) if guild_id.isdigit() else None
+        if guild is None:
+            embed = Embed(title="❌ Server Not Found", description=f"The bot is not in a server with ID `{guild_id}`.", color=discord.Color.red())
+            await safe_send_message(interaction, embed=embed, ephemeral=True)
+            return
+
+        embed = Embed(title=f"🏛️ {guild.name}", color=discord.Color.blue())
+        embed.add_field(name="ID", value=str(guild.id), inline=True)
+        embed.add_field(name="Members", value=str(guild.member_count), inline=True)
+        playing = current_radios.get(guild.id)
+        if playing:
+            embed.add_field(name="Playing", value=f"{playing['name']} ({format_duration(time.time() - playing.get('start_time', time.time()))})", inline=False)
+            if playing.get("channel_id"):
+                embed.add_field(name="Channel", value=f"<#{playing['channel_id']}>", inline=True)
+        else:
+            embed.add_field(name="Playing", value="Nothing", inline=False)
+        settings = guild_settings.get(guild.id, {})
+        embed.add_field(name="Blocked users", value=str(len(settings.get("blacklisted_users", []))), inline=True)
+        embed.add_field(name="Quality", value=settings.get("quality", "default"), inline=True)
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await safe_send_message(interaction, embed=embed, ephemeral=True)
+
+    @guild.command(name="stop", description="Stop playback on a server (bot owner only)")
+    @app_commands.describe(guild_id="Server ID")
+    async def guild_stop(self, interaction: Interaction, guild_id: str):
+        await self.check_owner(interaction, "stop playback on other servers")
+
+        station_name = await stop_station(self.bot, int(guild_id)) if guild_id.isdigit() else None
+        if station_name is None:
+            embed = Embed(title="❌ Nothing Playing", description=f"Nothing is playing on server `{guild_id}`.", color=discord.Color.red())
+        else:
+            logger.info(f"⏹️ Owner {interaction.user.display_name} stopped '{station_name}' on server {guild_id}")
+            embed = Embed(title="⏹️ Playback Stopped", description=f"Stopped **{station_name}** on server `{guild_id}`.", color=discord.Color.green())
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await safe_send_message(interaction, embed=embed, ephemeral=True)
+
+    @guild.command(name="stop-all", description="Stop playback on every server (bot owner only)")
+    async def guild_stop_all(self, interaction: Interaction):
+        await self.check_owner(interaction, "stop playback on all servers")
+
+        # Not remembered for restore: the owner wants these streams to stay stopped
+        stopped = await stop_all_stations(self.bot, remember=False)
+        logger.info(f"⏹️ Owner {interaction.user.display_name} stopped all {stopped} streams")
+
+        embed = Embed(
+            title="⏹️ All Playback Stopped",
+            description=f"Stopped **{stopped}** stream{'s' if stopped != 1 else ''}." if stopped else "Nothing was playing.",
+            color=discord.Color.green()
+        )
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await safe_send_message(interaction, embed=embed, ephemeral=True)
+
+    @shard.command(name="info", description="Show the servers and streams of this server's shard (bot owner only)")
+    async def shard_info(self, interaction: Interaction):
+        await self.check_owner(interaction, "inspect the shards")
+
+        shard_id = interaction.guild.shard_id
+        total_shards = self.bot.shard_count or 1
+        guilds = sorted((guild for guild in self.bot.guilds if guild.shard_id == shard_id), key=lambda guild: guild.name.lower())
+        streams = filter_by_shard(shard_id, total_shards)
+
+        embed = Embed(title=f"🧩 Shard {shard_id}", color=discord.Color.blue())
+        embed.add_field(name="Total shards", value=str(total_shards), inline=True)
+        embed.add_field(name="Servers", value=str(len(guilds)), inline=True)
+        embed.add_field(name="Active streams", value=str(len(streams)), inline=True)
+        lines = [f"{'📻 ' if guild.id in streams else ''}{guild.name} (`{guild.id}`)" for guild in guilds[:20]]
+        if len(guilds) > 20:
+            lines.append(f"…and {len(guilds) - 20} more")
+        embed.add_field(name="Managed servers", value="\n".join(lines)[:1024] or "None", inline=False)
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await safe_send_message(interaction, embed=embed, ephemeral=True)
+
+    @bot_config.command(name="reload", description="Reload the radio stations from the config file (bot owner only)")
+    async def config_reload(self, interaction: Interaction):
+        await self.check_owner(interaction, "reload the configuration")
+
+        config_path = os.getenv("CONFIG_PATH", "config.yaml")
+        try:
+            new_config = load_config(config_path)
+        except Exception as e:
+            embed = Embed(title="❌ Reload Failed", description=f"Could not load `{config_path}`:\n```{str(e)[:1000]}```", color=discord.Color.red())
+            await safe_send_message(interaction, embed=embed, ephemeral=True)
+            return
+
+        diff = diff_configs({"radios": RADIOS}, new_config)
+        RADIOS.clear()
+        RADIOS.update(new_config.get("radios") or {})
+        save_state()
+        logger.info(f"🔄 Owner {interaction.user.display_name} reloaded {len(RADIOS)} stations from {config_path}")
+        for label, names in (("Added", diff.added), ("Removed", diff.removed), ("Changed", diff.changed)):
+            if names:
+                logger.info(f"🔄 {label} stations: {', '.join(names)}")
+        # Streams keep playing, but the station can't be restarted or navigated from anymore
+        affected = [
+            guild_id for guild_id, radio_data in current_radios.items()
+            if radio_data.get("name") in diff.removed and radio_data.get("name") not in server_stations.get(guild_id, {})
+        ]
+        if affected:
+            logger.warning(f"⚠️ Removed stations are still playing in guilds {', '.join(str(guild_id) for guild_id in affected)}")
+
+        summary = f"{len(diff.added)} added, {len(diff.removed)} removed, {len(diff.changed)} changed" if diff else "No station changes"
+        embed = Embed(
+            title="🔄 Configuration Reloaded",
+            description=f"Loaded **{len(RADIOS)}** global stations from `{config_path}` ({summary}).\n\nBot options (prefix, intervals, ...) apply after a restart.",
+            color=discord.Color.green()
+        )
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await safe_send_message(interaction, embed=embed, ephemeral=True)
+
+    @bot_config.command(name="validate", description="Check the config file for problems (bot owner only)")
+    async def config_validate(self, interaction: Interaction):
+        await self.check_owner(interaction, "validate the configuration")
+
+        config_path = os.getenv("CONFIG_PATH", "config.yaml")
+        errors, warnings = [], []
+        # The raw file: load_config would already have dropped the invalid values
+        new_config = {}
+        try:
+            new_config = read_config(config_path)
+            check_tls(new_config)
+        except ConfigError as e:
+            errors.append(str(e))
+        except Exception as e:
+            errors.append(f"File could not be loaded: {str(e)[:500]}")
+        for problem in validate_config(new_config):
+            (errors if problem.is_error else warnings).append(f"**{problem.station}**: {problem.message}")
+
+        lines = [f"❌ {error}" for error in errors] + [f"⚠️ {warning}" for warning in warnings]
+        description = "\n".join(lines) if lines else "No problems found."
+        if len(description) > 4000:
+            description = description[:4000].rsplit("\n", 1)[0] + "\n…"
+        if errors:
+            title, color = f"❌ {len(errors)} Error{'s' if len(errors) != 1 else ''}, {len(warnings)} Warning{'s' if len(warnings) != 1 else ''}", discord.Color.red()
+        elif warnings:
+            title, color = f"⚠️ {len(warnings)} Warning{'s' if len(warnings) != 1 else ''}", discord.Color.yellow()
+        else:
+            title, color = "✅ Configuration Valid", discord.Color.green()
+        embed = Embed(title=title, description=description, color=color)
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await safe_send_message(interaction, embed=embed, ephemeral=True)
+
+    @bot_config.command(name="status", description="Show the active bot configuration (bot owner only)")
+    async def config_status(self, interaction: Interaction):
+        await self.check_owner(interaction, "view the configuration")
+
+        bot_options = config["bot"]
+        embed = Embed(title="⚙️ Configuration", color=discord.Color.blue())
+        embed.add_field(name="Config file", value=f"`{os.getenv('CONFIG_PATH', 'config.yaml')}`", inline=False)
+        embed.add_field(name="Global stations", value=str(len(RADIOS)), inline=True)
+        embed.add_field(name="Default quality", value=bot_options["default_quality"], inline=True)
+        embed.add_field(name="Shards", value=str(bot_options["shards"] or 1), inline=True)
+        embed.add_field(name="Health checks", value=f"every {bot_options['health_check_interval']}s" if bot_options["health_check_interval"] else "disabled", inline=True)
+        embed.add_field(name="Auto-leave", value=f"after {bot_options['auto_leave_delay']}s" if bot_options["auto_leave_empty"] else "disabled", inline=True)
+        embed.add_field(name="Restore on restart", value="yes" if bot_options["restore_on_restart"] else "no", inline=True)
+        embed.add_field(name="User rate limit", value=f"{bot_options['user_rate_limit_per_minute']}/min" if bot_options["user_rate_limit_per_minute"] else "disabled", inline=True)
+        embed.add_field(name="Failure webhook", value="configured" if bot_options["failure_webhook"] else "not set", inline=True)
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await safe_send_message(interaction, embed=embed, ephemeral=True)
+
+    @bot_stats.command(name="latency", description="Show how long slash commands take to respond (bot owner only)")
+    async def stats_latency(self, interaction: Interaction):
+        await self.check_owner(interaction, "view command latencies")
+
+        rows = command_latencies.summary()
+        if rows:
+            lines = [f"{'Command':<24} {'n':>3} {'p50':>7} {'p95':>7} {'p99':>7}"]
+            for name, count, (p50, p95, p99) in rows:
+                lines.append(f"{name[:24]:<24} {count:>3} {p50 * 1000:>5.0f}ms {p95 * 1000:>5.0f}ms {p99 * 1000:>5.0f}ms")
+            description = "```\n" + "\n".join(lines)[:3900] + "\n```"
+        else:
+            description = "No commands recorded since the last start or reset."
+        embed = Embed(
+            title="⏱️ Command Latency",
+            description=description,
+            color=discord.Color.blue()
+        )
+        health_rows = health_check_latencies.summary()
+        if health_rows:
+            lines = [f"{'Station':<24} {'n':>3} {'avg':>7} {'p95':>7} {'p99':>7}"]
+            for name, count, (_, p95, p99) in health_rows:
+                average = health_check_latencies.mean(name)
+                lines.append(f"{name[:24]:<24} {count:>3} {average * 1000:>5.0f}ms {p95 * 1000:>5.0f}ms {p99 * 1000:>5.0f}ms")
+            embed.add_field(name="🩺 Health Checks", value="```\n" + "\n".join(lines)[:1000] + "\n```", inline=False)
+        embed.set_footer(text=f"Alastor - The Radio Daemon • Last {command_latencies.max_samples} calls per command")
+        await safe_send_message(interaction, embed=embed, ephemeral=True)
+
+    @bot_stats.command(name="reset", description="Clear the recorded command latencies (bot owner only)")
+    async def stats_reset(self, interaction: Interaction):
+        await self.check_owner(interaction, "reset command latencies")
+
+        command_latencies.reset()
+        health_check_latencies.reset()
+        logger.info(f"🧹 Owner {interaction.user.display_name} reset command latencies")
+
+        embed = Embed(title="🧹 Latencies Reset", description="All recorded command and health check latencies were cleared.", color=discord.Color.green())
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await safe_send_message(interaction, embed=embed, ephemeral=True)
+
+async def setup(bot: commands.Bot):
+    await bot.add_cog(AdminCog(bot))
@@ -1,4 +1,3 @@
-import os
 import discord
 from discord.ext import commands
 from discord import app_commands, Interaction, Embed, ui
@@ -8,10 +7,10 @@ from pathlib import Path
 # Add the parent directory to sys.path to allow imports
 sys.path.insert(0, str(Path(__file__).parent.parent.parent))
 
-from src.config import load_config
+from src.config import get_config
 
-# Load configuration
-config = load_config(os.getenv("CONFIG_PATH", "config.yaml"))
+# Configuration loaded by bot.py
+config = get_config()
 DONATIONS = config.get("donations", [])
 GITHUB_URL = config.get("bot", {}).get("github_url", "https://github.com/bnfone/discord-bot-alastor")
 
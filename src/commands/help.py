@@ -19,8 +19,15 @@ class HelpCog(commands.Cog):
             name="📻 Radio Commands",
             value=(
                 "`/radio play <station>` - Play a specific radio station\n"
-                "`/radio list` - Browse all available stations\n"
+                "`/radio list [country] [language]` - Browse all available stations\n"
+                "`/radio random [genre]` - Play a random station\n"
                 "`/radio info` - Show current playing station details\n"
+                "`/radio stats` - Show playback statistics for this server\n"
+                "`/radio leaderboard` - Show the most played stations\n"
+                "`/radio health` - Show which stations are online (DJ)\n"
+                "`/radio browser search <query>` - Find stations in the Radio Browser directory\n"
+                "`/radio loop <on|off>` - Restart the station when its stream ends\n"
+                "`/radio alias <add|remove> <station> <alias>` - Manage alternative station names (DJ)\n"
                 "`/radio stop` - Stop radio and leave voice channel"
             ),
             inline=False
@@ -30,7 +37,7 @@ class HelpCog(commands.Cog):
         embed.add_field(
             name="ℹ️ General Commands", 
             value=(
-                "`/info` - Show bot information and version\n"
+                "`/info [format]` - Show bot information (also as JSON)\n"
                 "`/donate` - Support the bot development\n"
                 "`/help` - Show this help message"
             ),
@@ -42,7 +49,35 @@ class HelpCog(commands.Cog):
             name="🎵 Station Management (Admin Only)",
             value=(
                 "`/station add <name> <url>` - Add server radio station\n"
-                "`/station remove <name>` - Remove server radio station"
+                "`/station remove <name>` - Remove server radio station\n"
+                "`/admin station add <name> <url>` - Add global radio station\n"
+                "`/admin station import-wizard` - Add a global station step by step\n"
+                "`/admin station remove <name>` - Remove global radio station\n"
+                "`/admin station list` - List global radio stations\n"
+                "`/admin station export` - Download the global stations as M3U\n"
+                "`/admin station import <file>` - Add global stations from an M3U file\n"
+                "`/admin station disable|enable <name>` - Disable or re-enable a station\n"
+                "`/admin guild list|info|stop|stop-all` - Inspect servers (bot owner)\n"
+                "`/admin config reload|validate|status` - Manage the config (bot owner)\n"
+                "`/admin shard info` - Servers and streams of this shard (bot owner)\n"
+                "`/admin stats latency|reset` - Command response times (bot owner)\n"
+                "`/admin reset-stats` - Reset this server's playback statistics\n"
+                "`/admin blacklist add|remove|list` - Block users from using `/radio`"
+            ),
+            inline=False
+        )
+        
+        # Server Setup Commands
+        embed.add_field(
+            name="⚙️ Server Setup (Manage Server)",
+            value=(
+                "`/setup quality <low|medium|high>` - Set preferred stream quality\n"
+                "`/setup dj-role [role]` - Set the DJ role\n"
+                "`/setup player-channel [channel]` - Pin a now-playing message in a channel\n"
+                "`/setup sotd-channel [channel]` - Announce a station of the day\n"
+                "`/setup reactions <on|off>` - Control the player message with reactions\n"
+                "`/setup auto-join <on|off> [channel] [station]` - Play a station on startup\n"
+                "`/setup compact <on|off>` - Single-line now-playing messages"
             ),
             inline=False
         )
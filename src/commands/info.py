@@ -1,5 +1,7 @@
-import os
+import json
+import time
 import discord
+from dataclasses import asdict, dataclass
 from discord.ext import commands
 from discord import app_commands, Interaction, Embed
 import sys
@@ -8,18 +10,59 @@ from pathlib import Path
 # Add the parent directory to sys.path to allow imports
 sys.path.insert(0, str(Path(__file__).parent.parent.parent))
 
-from src.config import load_config
+from src.config import get_config
+from src.utils import format_duration
+from src.commands.radio import current_radios, stream_cache
 
-# Load configuration
-config = load_config(os.getenv("CONFIG_PATH", "config.yaml"))
+# Configuration loaded by bot.py
+config = get_config()
 BOT_DESCRIPTION = config.get("bot", {}).get("description", "A powerful and fun Discord radio bot inspired by Alastor.")
 
+def get_memory_usage() -> str:
+    """Resident memory of the bot process (Linux only, "Unknown" elsewhere)"""
+    try:
+        with open("/proc/self/status", "r") as f:
+            for line in f:
+                if line.startswith("VmRSS:"):
+                    return f"{int(line.split()[1]) / 1024:.1f} MB"
+    except (OSError, ValueError, IndexError):
+        pass
+    return "Unknown"
+
+@dataclass
+class BotStatus:
+    """Machine-readable bot status, e.g. for /info format:json"""
+    version: str
+    uptime_secs: int
+    active_streams: int
+    cached_streams: int
+    guilds: int
+
+    def to_json(self) -> str:
+        return json.dumps(asdict(self), indent=2)
+
 class InfoCog(commands.Cog):
     def __init__(self, bot: commands.Bot):
         self.bot = bot
+        self.started_at = time.monotonic()
+
+    def get_status(self) -> BotStatus:
+        return BotStatus(
+            version=str(config.get("bot", {}).get("version", "Unknown")),
+            uptime_secs=int(time.monotonic() - self.started_at),
+            active_streams=len(current_radios),
+            cached_streams=len(stream_cache),
+            guilds=len(self.bot.guilds)
+        )
 
     @app_commands.command(name="info", description="Show information about the bot.")
-    async def show_info(self, interaction: Interaction):
+    @app_commands.describe(format="Text (default) or JSON for monitoring scripts")
+    @app_commands.choices(format=[app_commands.Choice(name="Text", value="text"), app_commands.Choice(name="JSON", value="json")])
+    async def show_info(self, interaction: Interaction, format: app_commands.Choice[str] = None):
+        if format and format.value == "json":
+            await interaction.response.send_message(f"```json\n{self.get_status().to_json()}\n```", ephemeral=True)
+            return
+
         embed = Embed(
             title="Alastor - The Radio Daemon",
             description=BOT_DESCRIPTION,
@@ -28,6 +71,10 @@ class InfoCog(commands.Cog):
         version = config.get("bot", {}).get("version", "Unknown")
         github_url = config.get("bot", {}).get("github_url", "https://github.com/bnfone/discord-bot-alastor")
         embed.add_field(name="Version", value=version, inline=False)
+        embed.add_field(name="Uptime", value=format_duration(time.monotonic() - self.started_at), inline=True)
+        embed.add_field(name="Memory", value=get_memory_usage(), inline=True)
+        embed.add_field(name="Serving Guilds", value=str(len(self.bot.guilds)), inline=True)
+        embed.add_field(name="Active Streams", value=str(len(current_radios)), inline=True)
         embed.add_field(name="Developer", value="[Blake](https://github.com/bnfone)", inline=False)
         embed.add_field(name="Source Code", value=f"[GitHub]({github_url})", inline=False)
         embed.set_footer(text="Alastor - The Radio Daemon")
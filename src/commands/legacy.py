@@ -0,0 +1,134 @@
+import logging
+import discord
+from discord.ext import commands
+from discord import Embed
+import sys
+from pathlib import Path
+
+# Add the parent directory to sys.path to allow imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from src.errors import AlastorError, is_user_facing
+from src.commands.radio import (
+    check_station_access,
+    current_radios,
+    get_available_stations,
+    is_blacklisted,
+    rank_stations,
+    resolve_station_name,
+    start_station,
+    stop_station,
+)
+from src.tree import USER_RATE_LIMIT, user_rate_limiter, command_cooldown
+
+logger = logging.getLogger(__name__)
+
+DEPRECATION_NOTICE = "Prefer using slash commands (`/radio ...`); prefix commands may be removed in the future."
+
+async def reply(ctx: commands.Context, title: str, description: str, color: discord.Color):
+    embed = Embed(title=title, description=description, color=color)
+    embed.set_footer(text=f"Alastor - The Radio Daemon • {DEPRECATION_NOTICE}")
+    await ctx.reply(embed=embed, mention_author=False)
+
+class LegacyCog(commands.Cog):
+    """Prefix-based fallback (e.g. "!radio play Jazz FM") for servers where slash commands aren't available yet"""
+
+    def __init__(self, bot: commands.Bot):
+        self.bot = bot
+
+    async def cog_check(self, ctx: commands.Context) -> bool:
+        # Same rules as the slash commands: servers only, no blacklisted users
+        if ctx.guild is None or is_blacklisted(ctx.guild.id, ctx.author.id):
+            return False
+
+        # The rate limit and cooldowns are shared with the slash commands, so "!radio play" counts as "/radio play"
+        if USER_RATE_LIMIT:
+            retry_after = user_rate_limiter.hit(ctx.author.id)
+            if retry_after is not None:
+                logger.info(f"🐌 Rate limited {ctx.author} ({USER_RATE_LIMIT}/min)")
+                await reply(ctx, "🐌 Slow down!", f"Try again in a moment ({retry_after:.0f}s).", discord.Color.orange())
+                return False
+        retry_after = command_cooldown.hit(ctx.author.id, ctx.command.qualified_name)
+        if retry_after is not None:
+            logger.info(f"⏳ {ctx.prefix}{ctx.command.qualified_name} on cooldown for {ctx.author} ({retry_after:.1f}s left)")
+            await reply(ctx, "⏳ Cooldown", f"You must wait {retry_after:.1f}s before using this command again.", discord.Color.orange())
+            return False
+        return True
+
+    async def cog_command_error(self, ctx: commands.Context, error: commands.CommandError):
+        if isinstance(error, commands.CheckFailure):
+            # Blacklisted users, direct messages and rate limits (already answered in cog_check)
+            logger.debug(f"Ignored {ctx.prefix}{ctx.invoked_with} from {ctx.author}: {error}")
+            return
+        if isinstance(error, commands.UserInputError):
+            await reply(ctx, "Error", f"Usage: `{ctx.prefix}radio play <station>`", discord.Color.red())
+            return
+        # Errors raised inside a command arrive wrapped in CommandInvokeError; the traceback only helps for unexpected ones
+        original = getattr(error, "original", error)
+        logger.error(f"❌ {ctx.prefix}{ctx.invoked_with} failed for {ctx.author}: {original!r}",
+                     exc_info=None if is_user_facing(original) else original)
+        if is_user_facing(original):
+            await reply(ctx, original.title, original.description, discord.Color.red())
+
+    @commands.group(name="radio", invoke_without_command=True)
+    async def radio(self, ctx: commands.Context):
+        await reply(ctx, "📻 Radio", f"Usage: `{ctx.prefix}radio play <station>`, `{ctx.prefix}radio stop`, `{ctx.prefix}radio list`", discord.Color.blue())
+
+    @radio.command(name="play")
+    async def play(self, ctx: commands.Context, *, name: str):
+        available_stations = get_available_stations(ctx.guild.id)
+        station_name = resolve_station_name(ctx.guild.id, name) or name
+        if station_name not in available_stations:
+            suggestions = [station for station, _ in rank_stations(ctx.guild.id, name)]
+            description = f"Station **{name}** does not exist."
+            if suggestions:
+                description += "\n\nDid you mean: " + ", ".join(f"**{station}**" for station in suggestions) + "?"
+            description += f"\n\nUse `{ctx.prefix}radio list` to see available stations."
+            await reply(ctx, "❌ Station Not Found", description, discord.Color.red())
+            return
+        voice_channel = getattr(ctx.author.voice, "channel", None)
+        if voice_channel is None:
+            await reply(ctx, "Error", "You must be in a voice channel to play radio.", discord.Color.red())
+            return
+
+        try:
+            check_station_access(ctx.author, station_name, available_stations[station_name], voice_channel)
+        except AlastorError as e:
+            await reply(ctx, e.title, e.description, discord.Color.red())
+            return
+
+        logger.info(f"🎵 Prefix play request: '{station_name}' by {ctx.author.display_name} in '{ctx.guild.name}'")
+        async with ctx.typing():
+            try:
+                await start_station(self.bot, ctx.guild, voice_channel, station_name, ctx.author.id)
+            except AlastorError as e:
+                await reply(ctx, e.title, e.description, discord.Color.red())
+                return
+        await reply(ctx, "📻 Radio Started", f"**{station_name}** is now playing in {voice_channel.mention}.", discord.Color.green())
+
+    @radio.command(name="stop")
+    async def stop(self, ctx: commands.Context):
+        station_name = await stop_station(self.bot, ctx.guild.id)
+        if station_name is None:
+            await reply(ctx, "📻 No Radio Playing", "No radio is currently playing on this server.", discord.Color.yellow())
+            return
+        await reply(ctx, "⏹️ Radio Stopped", f"**{station_name}** has been stopped.", discord.Color.green())
+
+    @radio.command(name="list")
+    async def list_stations(self, ctx: commands.Context):
+        names = list(get_available_stations(ctx.guild.id))
+        description = "\n".join(f"• {name}" for name in names) or "No stations configured."
+        if len(description) > 4000:
+            description = description[:4000].rsplit("\n", 1)[0] + "\n…"
+        await reply(ctx, f"📻 Available Stations ({len(names)})", description, discord.Color.blue())
+
+    @radio.command(name="info")
+    async def info(self, ctx: commands.Context):
+        radio_data = current_radios.get(ctx.guild.id)
+        if radio_data is None:
+            await reply(ctx, "📻 Radio Status", "No radio is currently playing on this server.", discord.Color.yellow())
+            return
+        await reply(ctx, "📻 Current Radio Station", f"**{radio_data['name']}** is currently playing.", discord.Color.blue())
+
+async def setup(bot: commands.Bot):
+    await bot.add_cog(LegacyCog(bot))
@@ -1,12 +1,25 @@
 import os
+import re
+import base64
+import shlex
 import asyncio
 import logging
-import aiohttp
 import json
 import time
-from typing import Dict, Optional, List
+import math
+import random
+import datetime
+import difflib
+import ipaddress
+import socket
+from contextlib import asynccontextmanager
+from dataclasses import dataclass
+from enum import Enum
+from typing import Awaitable, Callable, Dict, Optional, List, Set, Tuple
+from urllib.parse import urljoin, urlparse
+import aiohttp
 import discord
-from discord.ext import commands
+from discord.ext import commands, tasks
 from discord import app_commands, Interaction, Embed, ui, SelectOption
 import sys
 from pathlib import Path
@@ -14,18 +27,62 @@ from pathlib import Path
 # Add the parent directory to sys.path to allow imports
 sys.path.insert(0, str(Path(__file__).parent.parent.parent))
 
-from src.config import load_config
+from src.config import get_config, save_stations, is_valid_language, parse_schedule
+from src.stats import stats_tracker
+from src.notifications import notify_stream_failure
+from src import radio_browser
+from src.tracing import instrument
+from src.utils import format_duration, format_ago, format_uptime_bar
+from src.icy import IcyReader, measure_stream_bitrate
+from src.errors import is_user_facing, AlastorError, VoiceConnectionError, StreamUnavailableError, PlaylistParseError, PermissionDeniedError, InsufficientPermissionsError, StationDisabledError, StationRestrictedError, ListenerLimitError, collect_errors
+from src import playlist
+from src.http_client import get_session, MAX_REDIRECTS
+from src.latency import health_check_latencies
+from src.ytdlp import is_youtube_url, extract_stream_url
+from src.ratelimit import ComponentCooldown
 
-# Load configuration (via CONFIG_PATH, default: config.yaml)
-config = load_config(os.getenv("CONFIG_PATH", "config.yaml"))
+# Configuration loaded by bot.py (via CONFIG_PATH, default: config.yaml)
+config = get_config()
 RADIOS = config.get("radios", {})
 
 # Enhanced state management
-current_radios: Dict[int, Dict] = {}  # guild_id -> {name, voice_client, url, start_time}
-stream_cache: Dict[str, Dict] = {}  # url -> {resolved_url, timestamp}
+current_radios: Dict[int, Dict] = {}  # guild_id -> {name, voice_client, url, channel_id, start_time}
+resume_sessions: Dict[int, Dict] = {}  # guild_id -> {name, channel_id}, streams interrupted by a shutdown
+stream_cache: Dict[str, Dict] = {}  # url -> {resolved_url, title, timestamp}
+health_status: Dict[str, "StationHealth"] = {}  # url -> result of the last health check
 server_stations: Dict[int, Dict[str, Dict]] = {}  # guild_id -> {station_name -> {url, description, added_by}}
+guild_settings: Dict[int, Dict] = {}  # guild_id -> {quality, blacklisted_users, ...}
+skip_votes: Dict[int, set] = {}  # guild_id -> user IDs that voted to skip the current station
+disabled_stations: set = set()  # station names that can't be played until re-enabled
+preload_tasks: set = set()  # running preload_station tasks, referenced so they aren't garbage collected
+component_cooldown = ComponentCooldown(config["bot"]["component_cooldown_ms"] / 1000)  # Against button spam
 STATE_FILE = "bot_state.json"
 CACHE_DURATION = 3600  # 1 hour cache
+HEALTH_CACHE_DURATION = 300  # Reuse health check results for 5 minutes when playing
+MAX_IMPORT_STATIONS = 50  # Per /admin station import
+BITRATE_SAMPLE_MS = 3000  # How long to listen when measuring a stream's bitrate
+PRELOAD_TIMEOUT = 3  # Seconds to spend resolving the next station in the background
+LOOP_STABLE_AFTER = 60  # Loop mode: a stream that played this long resets the backoff
+
+@dataclass
+class StationHealth:
+    last_checked: float
+    is_healthy: bool
+    consecutive_failures: int = 0
+    message: str = ""
+    bitrate: Optional[int] = None  # kbps, from the icy-br header
+    measured_bitrate: Optional[int] = None  # kbps, measured by the background check if neither config nor icy-br has it
+    detected_format: Optional[str] = None  # derived from the Content-Type header
+    homepage_reachable: Optional[bool] = None  # None if the station has no homepage
+    resolved_url: Optional[str] = None  # Stream URL after playlist resolution and redirects
+    icy_url: Optional[str] = None  # Station homepage from the icy-url header
+    thumbnail_url: Optional[str] = None  # Image found on the icy-url homepage, for stations without a thumbnail
+
+STATE_EXCLUDED_KEYS = ("auth",)  # Stream credentials stay in config.yaml and are never written to bot_state.json
+
+def without_credentials(station: Dict) -> Dict:
+    """A copy of the station without its stream credentials"""
+    return {key: value for key, value in station.items() if key not in STATE_EXCLUDED_KEYS}
 
 # Persistent state management
 def save_state():
@@ -36,15 +93,26 @@ def save_state():
                 str(guild_id): {
                     "name": data["name"],
                     "url": data.get("url", ""),
+                    "channel_id": data.get("channel_id"),
                     "start_time": data.get("start_time", time.time())
                 }
                 for guild_id, data in current_radios.items()
             },
-            "radios": RADIOS,
+            "radios": {name: without_credentials(station) for name, station in RADIOS.items()},
             "server_stations": {
                 str(guild_id): stations
                 for guild_id, stations in server_stations.items()
-            }
+            },
+            "stats": stats_tracker.to_dict(),
+            "resume_sessions": {
+                str(guild_id): session
+                for guild_id, session in resume_sessions.items()
+            },
+            "guild_settings": {
+                str(guild_id): settings
+                for guild_id, settings in guild_settings.items()
+            },
+            "disabled_stations": sorted(disabled_stations)
         }
         with open(STATE_FILE, "w") as f:
             json.dump(state, f, indent=2)
@@ -57,17 +125,91 @@ def load_state():
     try:
         with open(STATE_FILE, "r") as f:
             state = json.load(f)
-        RADIOS.update(state.get("radios", {}))
+        for name, saved in state.get("radios", {}).items():
+            # Credentials always come from the config, so a changed password isn't overwritten by an old copy
+            station = without_credentials(saved)
+            if RADIOS.get(name, {}).get("auth"):
+                station["auth"] = RADIOS[name]["auth"]
+            RADIOS[name] = station
         # Load server-specific stations
         loaded_server_stations = state.get("server_stations", {})
         for guild_id_str, stations in loaded_server_stations.items():
             server_stations[int(guild_id_str)] = stations
+        stats_tracker.load(state.get("stats", {}))
+        for guild_id_str, settings in state.get("guild_settings", {}).items():
+            guild_settings[int(guild_id_str)] = settings
+        disabled_stations.update(state.get("disabled_stations", []))
+        for guild_id_str, session in state.get("resume_sessions", {}).items():
+            resume_sessions[int(guild_id_str)] = session
+        # Streams still marked as active mean the bot was not shut down cleanly (crash, kill -9)
+        for guild_id_str, data in state.get("current_radios", {}).items():
+            if data.get("channel_id"):
+                resume_sessions.setdefault(int(guild_id_str), {"name": data["name"], "channel_id": data["channel_id"]})
         logging.info(f"Loaded state: {len(RADIOS)} global stations, {sum(len(s) for s in server_stations.values())} server stations")
     except FileNotFoundError:
         logging.info("No state file found, starting fresh")
     except Exception as e:
         logging.error(f"Failed to load state: {e}")
 
+STATION_METADATA_KEYS = ("added_by", "added_at")  # Kept in bot_state.json only, not in config.yaml
+
+def get_config_fields(station: Dict) -> Dict:
+    """A global station's fields as they belong in config.yaml, without the runtime metadata"""
+    return {key: value for key, value in station.items() if key not in STATION_METADATA_KEYS}
+
+def save_config_stations(changes: Dict[str, Optional[Dict]]):
+    """Write changed global stations back to the config file (None removes a station, see save_stations)"""
+    try:
+        save_stations(os.getenv("CONFIG_PATH", "config.yaml"), changes)
+    except Exception as e:
+        logging.error(f"Failed to save config: {e}")
+
+def add_global_station(name: str, url: str, added_by: int, **fields) -> Dict:
+    """Add a global station and persist it to the state and config file"""
+    station = {
+        "url": url,
+        "added_by": added_by,
+        "added_at": time.time()
+    }
+    station.update({key: value for key, value in fields.items() if value})
+    RADIOS[name] = station
+    save_state()
+    save_config_stations({name: get_config_fields(station)})
+    return station
+
+def export_stations_m3u() -> str:
+    """Global stations as an extended M3U playlist, e.g. to back them up or share them with another instance"""
+    lines = ["#EXTM3U"]
+    for name, station in RADIOS.items():
+        attributes = f' tvg-bitrate="{station["bitrate"]}"' if station.get("bitrate") else ""
+        lines += [f"#EXTINF:-1{attributes},{name}", station["url"]]
+    return "\n".join(lines) + "\n"
+
+def import_stations_from_m3u(content: str, added_by: int) -> Tuple[List[str], List[str]]:
+    """
+    Add the entries of an extended M3U playlist (like export_stations_m3u writes) as global stations.
+    Entries are named by their #EXTINF title. Untitled entries, duplicate names, unsafe URLs and
+    entries past MAX_IMPORT_STATIONS are skipped. The caller persists the stations.
+    Returns (imported names, skipped names or URLs). Raises ValueError if the playlist has no entries.
+    """
+    entries = playlist.parse_m3u_extended(content)
+    if not entries:
+        raise ValueError("The playlist contains no stream entries")
+    imported, skipped = [], []
+    for entry in entries:
+        name = (entry.title or "").strip()
+        if (not name or len(imported) >= MAX_IMPORT_STATIONS or not is_safe_url(entry.url)[0]
+                or any(existing.lower() == name.lower() for existing in RADIOS)):
+            skipped.append(name or entry.url)
+            continue
+        station = {"url": entry.url, "added_by": added_by, "added_at": time.time()}
+        bitrate = entry.attributes.get("tvg-bitrate", "")
+        if bitrate.isdigit():
+            station["bitrate"] = int(bitrate)
+        RADIOS[name] = station
+        imported.append(name)
+    return imported, skipped
+
 # Configure logging
 logger = logging.getLogger(__name__)
 
@@ -75,10 +217,14 @@ logger = logging.getLogger(__name__)
 load_state()
 logger.info(f"🎵 Radio module initialized with {len(RADIOS)} stations")
 
+@instrument()
 async def resolve_stream_url(url: str) -> Optional[str]:
     """
     Asynchronously resolve playlist URLs with caching.
+    YouTube URLs are resolved with yt-dlp instead.
     Returns the resolved URL or None if retrieval fails.
+    Raises PlaylistParseError if the playlist was downloaded but could not be parsed,
+    and StreamUnavailableError for YouTube URLs when yt-dlp is not configured.
     """
     # Check cache first
     if url in stream_cache:
@@ -87,43 +233,376 @@ async def resolve_stream_url(url: str) -> Optional[str]:
             return cache_entry["resolved_url"]
         else:
             del stream_cache[url]  # Remove expired cache
+
+    ytdlp_path = config["bot"].get("ytdlp_path")
+    if is_youtube_url(url) and not ytdlp_path:
+        raise StreamUnavailableError(
+            "This station is a YouTube stream, which can only be played with yt-dlp.\n\n"
+            "Ask the bot operator to install yt-dlp and set `ytdlp_path` in the config."
+        )
     
-    lower_url = url.lower()
-    if lower_url.endswith((".m3u", ".m3u8", ".pls")):
-        try:
-            async with aiohttp.ClientSession(timeout=aiohttp.ClientTimeout(total=5)) as session:
-                async with session.get(url) as response:
-                    response.raise_for_status()
-                    text = await response.text()
-                    
-                    # Handle .pls format
-                    if lower_url.endswith(".pls"):
-                        for line in text.splitlines():
-                            line = line.strip()
-                            if line.startswith("File") and "=" in line:
-                                stream_url = line.split("=", 1)[1]
-                                if stream_url.startswith(("http", "https")):
-                                    # Cache the result
-                                    stream_cache[url] = {
-                                        "resolved_url": stream_url,
-                                        "timestamp": time.time()
-                                    }
-                                    return stream_url
-                    else:
-                        # Handle .m3u/.m3u8 format
-                        for line in text.splitlines():
-                            line = line.strip()
-                            if line and not line.startswith("#") and line.startswith(("http", "https")):
-                                # Cache the result
-                                stream_cache[url] = {
-                                    "resolved_url": line,
-                                    "timestamp": time.time()
-                                }
-                                return line
-        except Exception as e:
-            logging.error(f"Error resolving playlist URL {url}: {e}")
-            return None
-    return url
+    try:
+        if is_youtube_url(url):
+            resolved_url, title = await extract_stream_url(url, ytdlp_path), None
+        else:
+            resolved_url, title = await playlist.resolve_stream_url(url, get_session(), MAX_REDIRECTS, auth=get_stream_auth(url))
+    except ValueError as e:
+        logging.error(f"Error parsing playlist {url}: {e}")
+        raise PlaylistParseError(url, str(e))
+    except Exception as e:
+        logging.error(f"Error resolving playlist URL {url}: {e}")
+        return None
+
+    # Cache the result
+    stream_cache[url] = {
+        "resolved_url": resolved_url,
+        "title": title,
+        "timestamp": time.time()
+    }
+    return resolved_url
+
+@instrument()
+async def check_stream_health(url: str, station_name: Optional[str] = None) -> tuple[bool, str]:
+    """
+    Check whether a stream (or playlist) URL is reachable.
+    Returns (is_healthy, message) where message describes the failure.
+    """
+    is_healthy, message, headers, final_url = await _probe_stream(url, station_name)
+    previous = health_status.get(url)
+    health_status[url] = StationHealth(
+        last_checked=time.time(),
+        is_healthy=is_healthy,
+        consecutive_failures=0 if is_healthy else (previous.consecutive_failures if previous else 0) + 1,
+        message=message,
+        bitrate=_parse_bitrate(headers.get("icy-br")),
+        detected_format=CODECS.get(headers.get("content-type", "").split(";")[0].strip().lower()),
+        homepage_reachable=previous.homepage_reachable if previous else None,  # Only updated by the background check
+        measured_bitrate=previous.measured_bitrate if previous else None,
+        icy_url=headers.get("icy-url"),
+        thumbnail_url=previous.thumbnail_url if previous else None,
+        resolved_url=final_url
+    )
+
+    # Alert operators when a station that used to work goes down
+    if previous and previous.is_healthy and not is_healthy:
+        await notify_stream_failure(station_name or url, url, reason=message)
+    return is_healthy, message
+
+async def get_cached_health(url: str, station_name: Optional[str] = None) -> tuple[bool, str]:
+    """Like check_stream_health, but reuses a result younger than HEALTH_CACHE_DURATION"""
+    cached = health_status.get(url)
+    if cached and time.time() - cached.last_checked < HEALTH_CACHE_DURATION:
+        return cached.is_healthy, cached.message
+    return await check_stream_health(url, station_name)
+
+def get_stream_details(station: Dict) -> str:
+    """
+    Bitrate and format like "128kbps MP3", from the station config or the last health check.
+    A detected format that differs from the configured one is added as e.g. "AAC, MP3 (auto)".
+    """
+    health = health_status.get(station["url"])
+    bitrate = station.get("bitrate") or (health.bitrate if health else None)
+    bitrate_text = f"{bitrate}kbps" if bitrate else None
+    if not bitrate and health and health.measured_bitrate:
+        bitrate_text = f"~{health.measured_bitrate}kbps (measured)"
+    configured = station.get("format")
+    detected = health.detected_format if health else None
+    codec = configured
+    if detected and detected.lower() != (configured or "").lower():
+        codec = ", ".join(part for part in (configured, f"{detected} (auto)") if part)
+    return " ".join(part for part in (bitrate_text, codec, get_fidelity(station)) if part)
+
+def get_fidelity(station: Dict) -> Optional[str]:
+    """Bit depth and sample rate like "24bit/96kHz" for high-fidelity stations, if configured"""
+    parts = []
+    if station.get("bit_depth"):
+        parts.append(f"{station['bit_depth']}bit")
+    if station.get("sample_rate"):
+        parts.append(f"{station['sample_rate'] / 1000:g}kHz")
+    return "/".join(parts) or None
+
+# FFmpeg sample formats per bit depth; there is no packed 24-bit format, so 24-bit audio uses s32
+SAMPLE_FORMATS = {8: "u8", 16: "s16", 24: "s32", 32: "s32"}
+
+def get_stream_input_options(station: Dict) -> str:
+    """
+    Extra FFmpeg input options for stations with a configured sample rate, bit depth, credentials or codec_args.
+    FFmpeg still resamples to Discord's 48kHz/16-bit output.
+    """
+    options = []
+    if station.get("sample_rate"):
+        options.append(f"-ar {int(station['sample_rate'])}")
+    if station.get("bit_depth") in SAMPLE_FORMATS:
+        options.append(f"-sample_fmt {SAMPLE_FORMATS[station['bit_depth']]}")
+    auth = get_stream_auth(station["url"])
+    if auth:
+        # A header instead of user:password@ in the URL, which ends up in logs
+        credentials = base64.b64encode(f"{auth.login}:{auth.password}".encode()).decode()
+        options.append(f"-headers {shlex.quote(f'Authorization: Basic {credentials}')}")
+    # Proxy options were already dropped by load_config
+    options.extend(shlex.quote(arg) for arg in station.get("codec_args") or [])
+    return " ".join(options)
+
+def get_health_emoji(url: str) -> str:
+    """🟢 healthy, 🔴 failing, 🟡 not checked yet"""
+    health = health_status.get(url)
+    if health is None:
+        return "🟡"
+    return "🟢" if health.is_healthy else "🔴"
+
+def get_discord_emoji(station: Optional[Dict]) -> str:
+    """The station's own emoji (e.g. a custom server emoji like <:jazz:123>), 📻 by default"""
+    return (station or {}).get("discord_emoji") or "📻"
+
+def get_station_emoji(name: str, station: Dict) -> str:
+    """⛔ for disabled stations, the health emoji otherwise"""
+    return "⛔" if name in disabled_stations else get_health_emoji(station["url"])
+
+async def check_homepage(url: str) -> bool:
+    """Whether a station's website answers (for monitoring only, never affects playback)"""
+    try:
+        async with get_session().head(url, allow_redirects=True) as response:
+            return response.status < 400
+    except Exception as e:
+        logger.debug(f"Homepage {url} unreachable: {e!r}")
+        return False
+
+def is_public_address(address: str) -> bool:
+    """Whether an IP address is reachable on the internet (not private, loopback, link-local, multicast or reserved)"""
+    ip = ipaddress.ip_address(address.split("%", 1)[0])  # Drop IPv6 scope IDs like fe80::1%eth0
+    return not (ip.is_private or ip.is_loopback or ip.is_link_local or ip.is_multicast
+                or ip.is_reserved or ip.is_unspecified)
+
+async def is_public_host(url: str) -> bool:
+    """Whether every address the URL's host resolves to is public, so the bot can't be pointed at the local network"""
+    parsed = urlparse(url)
+    if parsed.scheme not in ("http", "https") or not parsed.hostname:
+        return False
+    try:
+        addresses = await asyncio.get_running_loop().getaddrinfo(parsed.hostname, None, type=socket.SOCK_STREAM)
+    except OSError as e:
+        logger.debug(f"Could not resolve {parsed.hostname}: {e}")
+        return False
+    return bool(addresses) and all(is_public_address(address[4][0]) for address in addresses)
+
+THUMBNAIL_FETCH_TIMEOUT = 2  # Seconds per request while looking for a homepage image
+THUMBNAIL_MAX_BYTES = 100 * 1024  # Only the <head> matters; don't download whole pages
+OG_IMAGE_PATTERNS = (
+    re.compile(r"""<meta[^>]+property=["']og:image["'][^>]+content=["']([^"']+)["']""", re.IGNORECASE),
+    re.compile(r"""<meta[^>]+content=["']([^"']+)["'][^>]+property=["']og:image["']""", re.IGNORECASE)
+)
+
+REDIRECT_STATUSES = (301, 302, 303, 307, 308)
+
+@asynccontextmanager
+async def open_public_url(method: str, url: str, timeout: aiohttp.ClientTimeout):
+    """
+    Like get_session().request(), but follows redirects by hand and checks every hop with is_public_host,
+    so a public page can't redirect the bot into the local network. Raises ValueError for a refused hop.
+    """
+    for _ in range(MAX_REDIRECTS + 1):
+        if not await is_public_host(url):
+            raise ValueError(f"{url} is not on a public host")
+        async with get_session().request(method, url, allow_redirects=False, timeout=timeout) as response:
+            location = response.headers.get("location")
+            if response.status in REDIRECT_STATUSES and location:
+                url = urljoin(url, location)
+                continue
+            yield response
+            return
+    raise ValueError(f"More than {MAX_REDIRECTS} redirects")
+
+async def find_homepage_image(homepage: str) -> Optional[str]:
+    """
+    The og:image of a station homepage, or its /favicon.ico if there is none. None if neither is found.
+    The homepage comes from the stream's icy-url header, which the stream operator chooses,
+    so only public hosts are contacted.
+    """
+    timeout = aiohttp.ClientTimeout(total=THUMBNAIL_FETCH_TIMEOUT)
+    try:
+        async with open_public_url("GET", homepage, timeout) as response:
+            if response.status < 400 and "html" in response.headers.get("content-type", ""):
+                page = b""
+                while len(page) < THUMBNAIL_MAX_BYTES:
+                    chunk = await response.content.read(THUMBNAIL_MAX_BYTES - len(page))
+                    if not chunk:
+                        break
+                    page += chunk
+                text = page.decode("utf-8", errors="replace")
+                for pattern in OG_IMAGE_PATTERNS:
+                    match = pattern.search(text)
+                    if match:
+                        return urljoin(str(response.url), match.group(1).strip())
+        favicon = urljoin(homepage, "/favicon.ico")
+        async with open_public_url("HEAD", favicon, timeout) as response:
+            if response.status < 400 and response.headers.get("content-type", "").startswith("image/"):
+                return favicon
+    except Exception as e:
+        logger.debug(f"No image found on {homepage}: {e!r}")
+    return None
+
+async def fetch_missing_thumbnails(urls: List[str]):
+    """Look for a thumbnail on the icy-url homepage of streams without one; each stream is only looked up once"""
+    targets = [url for url in urls
+               if health_status.get(url) and health_status[url].icy_url and not health_status[url].thumbnail_url]
+    images = await asyncio.gather(*(find_homepage_image(health_status[url].icy_url) for url in targets))
+    for url, image in zip(targets, images):
+        if image:
+            health_status[url].thumbnail_url = image
+            logger.debug(f"Found thumbnail {image} for {url}")
+
+async def measure_missing_bitrates(urls: List[str]):
+    """Measure the bitrate of healthy streams that don't state it; each stream is only measured once"""
+    targets = []
+    for url in urls:
+        health = health_status.get(url)
+        if (health and health.is_healthy and not health.bitrate and not health.measured_bitrate
+                and (health.resolved_url or "").startswith(("http://", "https://"))):
+            targets.append(url)
+    if not targets:
+        return
+    results = await asyncio.gather(
+        *(measure_stream_bitrate(health_status[url].resolved_url, get_session(), BITRATE_SAMPLE_MS, get_stream_auth(url))
+          for url in targets),
+        return_exceptions=True
+    )
+    for url, result in zip(targets, results):
+        if isinstance(result, Exception):
+            logger.debug(f"Bitrate measurement for {url} failed: {result}")
+        elif result:
+            health_status[url].measured_bitrate = result
+            logger.debug(f"Measured ~{result}kbps for {url}")
+
+async def check_all_stations_health():
+    """Check all global and server stations (and their homepages) concurrently"""
+    urls: Dict[str, str] = {station["url"]: name for name, station in RADIOS.items()}
+    homepages: Dict[str, str] = {station["url"]: station["homepage"] for station in RADIOS.values() if station.get("homepage")}
+    for stations in server_stations.values():
+        for name, station in stations.items():
+            urls.setdefault(station["url"], name)
+            if station.get("homepage"):
+                homepages.setdefault(station["url"], station["homepage"])
+
+    results = await asyncio.gather(
+        *(check_stream_health(url, name) for url, name in urls.items()),
+        return_exceptions=True
+    )
+    checked, error = collect_errors(results)
+    if error:
+        logger.error(f"❌ Health checks failed with errors:\n{error}")
+    healthy = sum(1 for is_healthy, _ in checked if is_healthy)
+    logger.info(f"🩺 Health check finished: {healthy}/{len(urls)} stations healthy")
+
+    configured_bitrate = {station["url"] for stations in [RADIOS] + list(server_stations.values())
+                          for station in stations.values() if station.get("bitrate")}
+    await measure_missing_bitrates([url for url in urls if url not in configured_bitrate])
+    configured_thumbnail = {station["url"] for stations in [RADIOS] + list(server_stations.values())
+                            for station in stations.values() if station.get("thumbnail")}
+    await fetch_missing_thumbnails([url for url in urls if url not in configured_thumbnail])
+
+    reachable = await asyncio.gather(*(check_homepage(homepage) for homepage in homepages.values()))
+    for url, is_reachable in zip(homepages, reachable):
+        if url in health_status:
+            health_status[url].homepage_reachable = is_reachable
+    if homepages:
+        logger.info(f"🌐 Homepage check finished: {sum(reachable)}/{len(homepages)} reachable")
+
+    # Stop offering stations that keep failing; an admin has to re-enable them
+    threshold = config["bot"]["auto_disable_after"]
+    if not threshold:
+        return
+    all_stations = [RADIOS] + list(server_stations.values())
+    newly_disabled = []
+    for stations in all_stations:
+        for name, station in stations.items():
+            health = health_status.get(station["url"])
+            if name not in disabled_stations and health and health.consecutive_failures >= threshold:
+                disabled_stations.add(name)
+                newly_disabled.append(name)
+                logger.warning(f"⛔ Disabled '{name}' after {health.consecutive_failures} failed health checks")
+    if newly_disabled:
+        save_state()
+
+CODECS = {
+    "audio/mpeg": "MP3",
+    "audio/mp3": "MP3",
+    "audio/aac": "AAC",
+    "audio/aacp": "AAC+",
+    "audio/ogg": "OGG",
+    "application/ogg": "OGG",
+    "audio/opus": "Opus",
+    "audio/flac": "FLAC",
+    "application/x-mpegurl": "HLS",
+    "application/vnd.apple.mpegurl": "HLS",
+    "audio/x-mpegurl": "M3U",
+    "audio/mpegurl": "M3U"
+}
+
+def _parse_bitrate(value: Optional[str]) -> Optional[int]:
+    # Some servers send "128,128" for multiple streams
+    try:
+        return int(value.split(",")[0]) if value else None
+    except ValueError:
+        return None
+
+def get_station_urls(station: Dict) -> List[str]:
+    """The station's stream URL, its quality tier URLs and its mirrors"""
+    return [station["url"]] + list((station.get("quality_urls") or {}).values()) + list(station.get("retry_urls") or [])
+
+def get_stream_auth(url: str) -> Optional[aiohttp.BasicAuth]:
+    """HTTP Basic credentials of the station with this URL, for password-protected Icecast streams"""
+    for stations in [RADIOS] + list(server_stations.values()):
+        for station in stations.values():
+            auth = station.get("auth")
+            if url in get_station_urls(station) and isinstance(auth, dict) and auth.get("username"):
+                return aiohttp.BasicAuth(str(auth["username"]), str(auth.get("password") or ""))
+    return None
+
+def requires_tls(url: str) -> bool:
+    """Whether a station with this URL (or mirror) is marked tls: true, so it may only be streamed over HTTPS"""
+    return any(station.get("tls") and url in [station["url"]] + list(station.get("retry_urls") or [])
+               for stations in [RADIOS] + list(server_stations.values()) for station in stations.values())
+
+async def _probe_stream(url: str, station_name: Optional[str] = None) -> tuple[bool, str, Dict[str, str], Optional[str]]:
+    """Returns (is_healthy, message, lower-cased response headers, final stream URL after redirects)"""
+    https_only = requires_tls(url)
+    try:
+        resolved_url = await resolve_stream_url(url)
+    except PlaylistParseError as e:
+        return False, f"Invalid playlist: {e.reason}", {}, None
+    except StreamUnavailableError:
+        return False, "YouTube stream, but ytdlp_path is not configured", {}, None
+    if resolved_url is None:
+        return False, "Could not resolve playlist URL", {}, None
+    if https_only and not resolved_url.startswith("https://"):
+        return False, "Stream is not served over HTTPS (tls: true)", {}, resolved_url
+    if not resolved_url.startswith(("http://", "https://")):
+        # e.g. mmsh:// from ASX playlists; only FFmpeg can open these
+        return True, "Stream can't be probed over HTTP", {}, resolved_url
+
+    started_at = time.perf_counter()
+    result = await _head_stream(url, resolved_url, https_only)
+    health_check_latencies.record_since(station_name or url, started_at, failed=not result[0])
+    return result
+
+async def _head_stream(url: str, resolved_url: str, https_only: bool) -> tuple[bool, str, Dict[str, str], Optional[str]]:
+    """The HTTP part of _probe_stream: a HEAD request to the resolved stream URL"""
+    try:
+        async with get_session().head(resolved_url, allow_redirects=True, max_redirects=MAX_REDIRECTS,
+                                      auth=get_stream_auth(url)) as response:
+            logger.info(f"📡 Stream test response: {response.status} - {response.headers.get('content-type', 'unknown')}")
+            redirects = playlist.describe_redirects(response)
+            if redirects:
+                logger.debug(f"Stream redirected: {redirects}")
+            headers = {key.lower(): value for key, value in response.headers.items()}
+            final_url = str(response.url)
+            if https_only and not final_url.startswith("https://"):
+                return False, "Stream redirected to a non-HTTPS URL (tls: true)", headers, final_url
+            if response.status >= 400:
+                return False, f"Stream returned HTTP {response.status}", headers, final_url
+    except Exception as e:
+        return False, str(e) or type(e).__name__, {}, None
+    return True, "Stream is reachable", headers, final_url
 
 async def safe_send_message(interaction: Interaction, embed: Embed = None, content: str = None, ephemeral: bool = False, view: ui.View = None):
     """Sends a response, even if one was already sent."""
@@ -140,6 +619,602 @@ async def safe_send_message(interaction: Interaction, embed: Embed = None, conte
     else:
         await interaction.followup.send(**kwargs)
 
+# Friendly messages for errors that don't carry their own; anything else is an internal error
+ERROR_MESSAGES = (
+    (app_commands.NoPrivateMessage, "❌ Servers Only", "This command only works in a server."),
+    (app_commands.MissingPermissions, "❌ Permission Denied", "You don't have the permissions this command needs."),
+    (app_commands.BotMissingPermissions, "❌ Missing Permissions", "I'm missing permissions for this command in this channel."),
+    (app_commands.TransformerError, "❌ Invalid Input", "One of the options has a value I can't use."),
+    (discord.Forbidden, "❌ Missing Permissions", "Discord refused the request. I'm probably missing a permission in this channel."),
+    (discord.NotFound, "❌ Not Found", "The channel or message no longer exists.")
+)
+
+def build_error_embed(error: Exception) -> Embed:
+    """Error embed for the user: the error's own message if it has one, otherwise a friendly or generic one"""
+    if is_user_facing(error):
+        title, description = error.title, error.description
+    else:
+        title, description = next(
+            ((title, description) for error_type, title, description in ERROR_MESSAGES if isinstance(error, error_type)),
+            ("❌ Internal Error", "Something went wrong on my side. Please try again later.")
+        )
+    embed = Embed(title=title, description=description, color=discord.Color.red())
+    embed.set_footer(text="Alastor - The Radio Daemon")
+    return embed
+
+async def send_error_embed(interaction: Interaction, error: Exception):
+    """Report an error to the user, once per interaction"""
+    if interaction.extras.get("error_reported"):
+        return
+    interaction.extras["error_reported"] = True
+    try:
+        await safe_send_message(interaction, embed=build_error_embed(error), ephemeral=True)
+    except discord.HTTPException as e:
+        logger.error(f"❌ Could not report error to user: {e}")
+
+class ResponseGuard:
+    """
+    Async context manager for slow handlers: send the final response through guard.send() or
+    guard.edit_original(). If the handler raises an internal error before that, the user gets an
+    error message instead of Discord's "This interaction failed". The exception still propagates;
+    user-facing errors are left to the command tree's error handler.
+    """
+
+    def __init__(self, interaction: Interaction, replace_original: bool = False):
+        self.interaction = interaction
+        self.replace_original = replace_original  # Edit e.g. a loading message instead of sending a new one
+        self.sent = False
+
+    async def send(self, **kwargs):
+        self.sent = True
+        await safe_send_message(self.interaction, **kwargs)
+
+    async def edit_original(self, **kwargs):
+        self.sent = True
+        await self.interaction.edit_original_response(**kwargs)
+
+    async def __aenter__(self) -> "ResponseGuard":
+        return self
+
+    async def __aexit__(self, exc_type, exc, traceback) -> bool:
+        if exc is None or self.sent or isinstance(exc, asyncio.CancelledError) or is_user_facing(exc):
+            return False
+        if not self.replace_original:
+            await send_error_embed(self.interaction, exc)
+            return False
+        try:
+            await self.edit_original(embed=build_error_embed(exc), content=None, view=None)
+            self.interaction.extras["error_reported"] = True
+        except discord.HTTPException as e:
+            logger.error(f"❌ Could not report error to user: {e}")
+        return False
+
+def record_session(guild_id: int, radio_data: Dict):
+    """Add a finished playback session to the guild statistics"""
+    stats_tracker.record_play(
+        guild_id,
+        radio_data["name"],
+        radio_data.get("requested_by"),
+        time.time() - radio_data.get("start_time", time.time())
+    )
+
+presence_state = {"index": 0, "shown": None}  # Position in the station rotation and the status currently set
+
+async def update_presence(client, advance: bool = False):
+    """
+    Show "Listening to <station>", rotating through the stations playing on different servers
+    (advance moves on to the next one), or the idle status while nothing plays.
+    """
+    station_names = sorted({radio_data["name"] for radio_data in current_radios.values()})
+    if station_names:
+        if advance:
+            presence_state["index"] += 1
+        name = station_names[presence_state["index"] % len(station_names)]
+        activity = discord.Activity(type=discord.ActivityType.listening, name=name)
+    else:
+        name = config["bot"]["idle_status"]
+        activity = discord.Activity(type=discord.ActivityType.watching, name=name)
+    if (activity.type, name) == presence_state["shown"]:
+        return  # e.g. a single station doesn't rotate
+    presence_state["shown"] = (activity.type, name)
+    await client.change_presence(activity=activity)
+
+def stop_stream_tasks(guild_id: int, radio_data: Dict):
+    """Cancel the background tasks (ICY reader, watchdog) attached to a stream"""
+    for key in ("icy_reader", "watchdog"):
+        if radio_data.get(key):
+            radio_data[key].stop()
+    # Votes only count for the station they were cast for
+    skip_votes.pop(guild_id, None)
+
+@instrument()
+async def stop_station(client, guild_id: int) -> Optional[str]:
+    """
+    Stop playback in a guild and leave the voice channel.
+    Returns the name of the stopped station or None if nothing was playing.
+    """
+    radio_data = current_radios.pop(guild_id, None)
+    if radio_data is None:
+        return None
+
+    stop_stream_tasks(guild_id, radio_data)
+
+    voice_client = radio_data["voice_client"]
+    guild_name = voice_client.guild.name if voice_client and voice_client.guild else "Unknown"
+
+    # Graceful shutdown
+    try:
+        voice_client.stop()
+        await asyncio.wait_for(voice_client.disconnect(force=True), timeout=5.0)
+        logger.info(f"✅ Successfully stopped and disconnected from '{guild_name}'")
+    except asyncio.TimeoutError:
+        logger.warning(f"⏰ Voice client disconnect timed out for '{guild_name}'")
+    except Exception as e:
+        logger.error(f"❌ Error disconnecting from '{guild_name}': {e}")
+
+    record_session(guild_id, radio_data)
+    save_state()
+    await update_presence(client)
+    await upsert_player_message(client, guild_id)
+    return radio_data["name"]
+
+def get_station_uptime(guild_id: int) -> Optional[float]:
+    """Seconds since the guild's current station started, or None if nothing is playing"""
+    radio_data = current_radios.get(guild_id)
+    if radio_data is None:
+        return None
+    return max(time.time() - radio_data.get("start_time", time.time()), 0)
+
+def count_listeners(radio_data: Dict) -> Optional[int]:
+    """Members other than bots in the stream's voice channel, or None if the channel isn't cached"""
+    guild = getattr(radio_data.get("voice_client"), "guild", None)
+    channel = guild.get_channel(radio_data["channel_id"]) if guild and radio_data.get("channel_id") else None
+    if channel is None:
+        return None
+    return sum(1 for member in channel.members if not member.bot)
+
+def format_uptime_field(uptime: float) -> str:
+    """Embed field value: HH:MM:SS above the uptime bar"""
+    return f"{format_duration(uptime)}\n`{format_uptime_bar(uptime)}`"
+
+def get_all_active_streams() -> List[Tuple[int, Dict]]:
+    """Snapshot of (guild_id, current_radios entry) for every guild with an active stream"""
+    return list(current_radios.items())
+
+def get_guild_shard(guild_id: int, total_shards: int) -> int:
+    """The shard Discord routes a guild through"""
+    return (guild_id >> 22) % total_shards
+
+def filter_by_shard(shard_id: int, total_shards: int) -> List[int]:
+    """Guilds with an active stream that belong to the given shard"""
+    return [guild_id for guild_id in current_radios if get_guild_shard(guild_id, total_shards) == shard_id]
+
+def get_own_guild_ids() -> List[int]:
+    """Guilds with an active stream that this process is responsible for (all unless shard_id is set)"""
+    shard_id = config["bot"]["shard_id"]
+    if shard_id is None:
+        return list(current_radios)
+    return filter_by_shard(shard_id, config["bot"]["shards"])
+
+async def stop_all_stations(client, remember: bool = True) -> int:
+    """
+    Stop every active stream this process is responsible for, e.g. on shutdown. Returns the number of stopped streams.
+    With remember, the interrupted streams are kept in resume_sessions so they can be restored on the next start.
+    """
+    guild_ids = get_own_guild_ids()
+    resume_sessions.clear()
+    if remember:
+        for guild_id in guild_ids:
+            radio_data = current_radios[guild_id]
+            if radio_data.get("channel_id"):
+                resume_sessions[guild_id] = {"name": radio_data["name"], "channel_id": radio_data["channel_id"]}
+
+    _, error = collect_errors(await asyncio.gather(
+        *(stop_station(client, guild_id) for guild_id in guild_ids),
+        return_exceptions=True
+    ))
+    if error:
+        logger.error(f"❌ Some stations could not be stopped:\n{error}")
+    save_state()
+    logger.info(f"⏹️ Stopped all stations ({len(resume_sessions)} remembered for restore)")
+    return len(guild_ids)
+
+async def prune_stale_streams(client) -> int:
+    """
+    Forget streams whose voice connection is gone (e.g. the bot was disconnected by a moderator
+    or the channel was deleted), so state, presence and player messages match reality.
+    Returns the number of removed entries.
+    """
+    stale = [guild_id for guild_id, radio_data in current_radios.items()
+             if radio_data.get("voice_client") is None or not radio_data["voice_client"].is_connected()]
+    for guild_id in stale:
+        radio_data = current_radios.pop(guild_id, None)
+        if radio_data is None:
+            continue
+        stop_stream_tasks(guild_id, radio_data)
+        record_session(guild_id, radio_data)
+        logger.warning(f"🧹 Removed stale stream '{radio_data['name']}' in guild {guild_id} (voice disconnected)")
+        await upsert_player_message(client, guild_id)
+    if stale:
+        save_state()
+        await update_presence(client)
+    return len(stale)
+
+async def restore_stations(client):
+    """Restart the streams that were interrupted by the last shutdown or crash"""
+    sessions = dict(resume_sessions)
+    resume_sessions.clear()
+    save_state()
+    if sessions:
+        logger.info(f"🔁 Restoring {len(sessions)} interrupted stream{'s' if len(sessions) != 1 else ''}")
+
+    for guild_id, session in sessions.items():
+        guild = client.get_guild(guild_id)
+        voice_channel = guild.get_channel(session["channel_id"]) if guild else None
+        if voice_channel is None:
+            logger.warning(f"⚠️ Cannot restore '{session['name']}': server or channel {session['channel_id']} no longer available")
+            continue
+        if session["name"] not in get_available_stations(guild_id):
+            logger.warning(f"⚠️ Cannot restore '{session['name']}' in '{guild.name}': station no longer exists")
+            continue
+        try:
+            await start_station(client, guild, voice_channel, session["name"])
+            logger.info(f"🔁 Restored '{session['name']}' in '{guild.name}'")
+        except Exception as e:
+            logger.error(f"❌ Failed to restore '{session['name']}' in '{guild.name}': {e}")
+
+AUTO_JOIN_STAGGER = 0.5  # Seconds between auto-joins, so Discord doesn't rate-limit the voice connections
+schedule_targets: Dict[int, str] = {}  # guild_id -> station the schedule picked last, to only switch when it changes
+
+def get_scheduled_station(guild_id: int, when: Optional[datetime.datetime] = None) -> Optional[str]:
+    """The station whose schedule covers the given time (default: now, UTC). The narrowest window wins."""
+    when = when or datetime.datetime.now(datetime.timezone.utc)
+    matches = []
+    for name, station in get_available_stations(guild_id).items():
+        if name in disabled_stations:
+            continue
+        for window in parse_schedule(station):
+            if window.matches(when):
+                matches.append((len(window.hours()), name))
+    return min(matches)[1] if matches else None
+
+def get_auto_join_station(guild_id: int) -> Optional[str]:
+    """The scheduled station of an auto-join guild, or its default station outside all schedules"""
+    return get_scheduled_station(guild_id) or guild_settings.get(guild_id, {}).get("default_station")
+
+async def apply_station_schedules(client):
+    """Switch auto-join guilds that are playing to the scheduled station when a schedule window starts or ends"""
+    for guild_id, settings in list(guild_settings.items()):
+        if not settings.get("auto_join"):
+            continue
+        target = get_auto_join_station(guild_id)
+        previous = schedule_targets.get(guild_id)
+        schedule_targets[guild_id] = target
+        radio_data = current_radios.get(guild_id)
+        # Leave manual picks alone until the schedule changes, and don't restart stopped guilds
+        if previous is None or target == previous or radio_data is None or target is None or radio_data["name"] == target:
+            continue
+        guild = client.get_guild(guild_id)
+        voice_channel = getattr(radio_data["voice_client"], "channel", None)
+        if guild is None or voice_channel is None:
+            continue
+        try:
+            await start_station(client, guild, voice_channel, target)
+            logger.info(f"🕒 Switched '{guild.name}' to scheduled station '{target}'")
+        except Exception as e:
+            logger.error(f"❌ Failed to switch '{guild.name}' to scheduled station '{target}': {e}")
+
+async def auto_join_stations(client):
+    """Start the default station in the default voice channel of every guild with auto-join (set via /setup auto-join)"""
+    for guild_id, settings in list(guild_settings.items()):
+        if not settings.get("auto_join") or guild_id in current_radios:
+            continue
+        guild = client.get_guild(guild_id)
+        if guild is None:
+            continue  # Handled by another shard, or the bot left
+        voice_channel = guild.get_channel(settings.get("default_voice_channel") or 0)
+        station_name = get_auto_join_station(guild_id)
+        schedule_targets[guild_id] = station_name
+        if voice_channel is None or station_name not in get_available_stations(guild_id):
+            logger.warning(f"⚠️ Cannot auto-join in '{guild.name}': voice channel or station '{station_name}' no longer available")
+            continue
+        try:
+            await start_station(client, guild, voice_channel, station_name)
+            logger.info(f"🚪 Auto-joined #{voice_channel.name} in '{guild.name}' with '{station_name}'")
+        except Exception as e:
+            logger.error(f"❌ Failed to auto-join in '{guild.name}': {e}")
+        await asyncio.sleep(AUTO_JOIN_STAGGER)
+
+def build_player_embed(guild_id: int) -> Embed:
+    """Now-playing embed for the guild's player channel, or an idle embed when nothing plays"""
+    radio_data = current_radios.get(guild_id)
+    if radio_data is None:
+        embed = Embed(
+            title="📻 Nothing is playing",
+            description="Use `/radio play` or `/radio list` to start a station.",
+            color=discord.Color.dark_grey()
+        )
+    else:
+        station_name = radio_data["name"]
+        embed = Embed(
+            title=f"{get_discord_emoji(get_available_stations(guild_id).get(station_name))} Now Playing"
+                  + (" 🔁" if is_looping(guild_id) else ""),
+            description=f"**{station_name}**",
+            color=discord.Color.green()
+        )
+        if radio_data.get("current_title"):
+            embed.add_field(name="🎶 Track", value=radio_data["current_title"][:1024], inline=False)
+        if radio_data.get("channel_id"):
+            embed.add_field(name="🔊 Channel", value=f"<#{radio_data['channel_id']}>", inline=True)
+        listeners = count_listeners(radio_data)
+        if listeners is not None:
+            embed.add_field(name="🎧 Listeners", value=str(listeners), inline=True)
+        quality = radio_data.get("quality")
+        embed.add_field(name="🎵 Quality", value=quality.capitalize() if quality else "Auto", inline=True)
+        if radio_data.get("requested_by"):
+            embed.add_field(name="🎧 Requested by", value=f"<@{radio_data['requested_by']}>", inline=True)
+        embed.add_field(name="🕰️ Uptime", value=format_uptime_field(get_station_uptime(guild_id)), inline=False)
+        thumbnail = get_station_thumbnail(get_available_stations(guild_id).get(station_name))
+        if thumbnail:
+            embed.set_thumbnail(url=thumbnail)
+    embed.set_footer(text="Alastor - The Radio Daemon")
+    return embed
+
+def is_looping(guild_id: int) -> bool:
+    """Whether the guild restarts the station when its stream ends (set via /radio loop)"""
+    return guild_settings.get(guild_id, {}).get("loop", False)
+
+def is_compact(guild_id: int) -> bool:
+    """Whether the guild prefers single-line messages over embeds (set via /setup compact)"""
+    return guild_settings.get(guild_id, {}).get("compact", False)
+
+def build_compact_line(guild_id: int) -> str:
+    """Single-line now-playing summary: 🎵 **Jazz FM** | 128kbps MP3 | Started by @user | 5m ago"""
+    radio_data = current_radios.get(guild_id)
+    if radio_data is None:
+        return "📻 Nothing is playing"
+    station_name = radio_data["name"]
+    parts = [f"🎵 **{station_name}**"]
+    station = get_available_stations(guild_id).get(station_name)
+    details = get_stream_details(station) if station else ""
+    if details:
+        parts.append(details)
+    if radio_data.get("requested_by"):
+        parts.append(f"Started by <@{radio_data['requested_by']}>")
+    parts.append(format_ago(time.time() - radio_data.get("start_time", time.time())))
+    if is_looping(guild_id):
+        parts.append("🔁")
+    return " | ".join(parts)
+
+# Player message controls for clients that don't render buttons well (/setup reactions)
+PLAYER_REACTIONS = {"⏮": "previous", "🔇": "stop", "⏭": "next", "🔁": "loop"}
+
+def uses_reactions(guild_id: int) -> bool:
+    """Whether the guild controls the player message with reactions (set via /setup reactions)"""
+    return guild_settings.get(guild_id, {}).get("use_reactions", False)
+
+async def sync_player_reactions(client, guild_id: int, message: discord.Message):
+    """Add the control reactions to the player message, or remove the bot's own when reactions are off"""
+    own = {str(reaction.emoji).replace("\ufe0f", "") for reaction in message.reactions if reaction.me}
+    try:
+        if uses_reactions(guild_id):
+            for emoji in PLAYER_REACTIONS:
+                if emoji not in own:
+                    await message.add_reaction(emoji)
+        else:
+            for emoji in own & set(PLAYER_REACTIONS):
+                await message.remove_reaction(emoji, client.user)
+    except discord.Forbidden:
+        logger.warning(f"⚠️ Missing permission to add reactions to the player message in guild {guild_id}")
+
+async def handle_player_reaction(client, payload: discord.RawReactionActionEvent):
+    """Run the player control behind a reaction on the guild's player message, then remove the reaction"""
+    settings = guild_settings.get(payload.guild_id, {})
+    action = PLAYER_REACTIONS.get(str(payload.emoji).replace("\ufe0f", ""))
+    if (action is None or payload.message_id != settings.get("player_message") or not uses_reactions(payload.guild_id)
+            or payload.user_id == client.user.id):
+        return
+
+    guild = client.get_guild(payload.guild_id)
+    channel = client.get_channel(payload.channel_id)
+    member = payload.member or (guild.get_member(payload.user_id) if guild else None)
+    if guild is None or channel is None or member is None:
+        return
+    try:
+        # Reactions work like buttons: one tap, then the count goes back to 1
+        message = await channel.fetch_message(payload.message_id)
+        await message.remove_reaction(payload.emoji, member)
+    except discord.HTTPException:
+        pass  # Without Manage Messages the reaction just stays
+
+    radio_data = current_radios.get(guild.id)
+    voice_channel = getattr(radio_data["voice_client"], "channel", None) if radio_data else None
+    # Like the buttons, controls are for the people listening
+    if voice_channel is None or member not in voice_channel.members or is_blacklisted(guild.id, member.id):
+        return
+
+    logger.info(f"👆 {member.display_name} used the {action} reaction in {guild.name}")
+    if action == "stop":
+        await stop_station(client, guild.id)
+    elif action == "loop":
+        settings["loop"] = not is_looping(guild.id)
+        save_state()
+        await upsert_player_message(client, guild.id)
+    else:
+        station_name = get_adjacent_station(guild.id, Direction.PREV if action == "previous" else Direction.NEXT)
+        if station_name is None:
+            return
+        try:
+            check_station_access(member, station_name, get_available_stations(guild.id)[station_name], voice_channel)
+            await start_station(client, guild, voice_channel, station_name, member.id)
+        except (StationRestrictedError, ListenerLimitError):
+            pass  # Already logged, and reactions have nowhere to show the reason
+        except AlastorError as e:
+            logger.error(f"❌ Switching to '{station_name}' via reaction failed: {e.description}")
+
+async def upsert_player_message(client, guild_id: int):
+    """
+    Create or update the pinned player message in the guild's player channel (set via /setup player-channel).
+    Errors are logged only; the player message must never break playback.
+    """
+    settings = guild_settings.get(guild_id, {})
+    channel_id = settings.get("player_channel")
+    if not channel_id:
+        return
+    channel = client.get_channel(channel_id)
+    if channel is None:
+        logger.warning(f"⚠️ Player channel {channel_id} of guild {guild_id} no longer exists")
+        return
+
+    if is_compact(guild_id):
+        content, embed = build_compact_line(guild_id), None
+    else:
+        content, embed = None, build_player_embed(guild_id)
+    try:
+        message_id = settings.get("player_message")
+        if message_id:
+            try:
+                message = await channel.fetch_message(message_id)
+                await message.edit(content=content, embed=embed)
+                await sync_player_reactions(client, guild_id, message)
+                return
+            except discord.NotFound:
+                pass  # Deleted by someone, post a new one
+
+        message = await channel.send(content=content, embed=embed)
+        settings["player_message"] = message.id
+        save_state()
+        try:
+            await message.pin()
+        except discord.Forbidden:
+            logger.warning(f"⚠️ Missing permission to pin the player message in #{channel.name}")
+        await sync_player_reactions(client, guild_id, message)
+    except discord.HTTPException as e:
+        logger.error(f"❌ Failed to update player message in guild {guild_id}: {e}")
+
+async def send_play_notification(interaction: Interaction, station_name: str):
+    """Short ephemeral confirmation for the user who started a station, deleted after play_notification_ttl_secs"""
+    ttl = config["bot"]["play_notification_ttl_secs"]
+    if not ttl:
+        return
+    player_channel = guild_settings.get(interaction.guild_id, {}).get("player_channel")
+    where = f"in <#{player_channel}>" if player_channel else "below"
+    try:
+        message = await interaction.followup.send(
+            f"✅ Now playing **{station_name}** — use the player {where} to control playback.",
+            ephemeral=True, wait=True
+        )
+        # Deleting later ignores errors, e.g. 404 when the user already dismissed the message
+        await message.delete(delay=ttl)
+    except discord.HTTPException as e:
+        logger.debug(f"Could not send play notification: {e}")
+
+owner_ids: Set[int] = set()  # Filled on startup by load_owner_ids
+
+async def load_owner_ids(client):
+    """Use owner_id from the config, or fall back to the application owner (or its team members)"""
+    owner_ids.clear()
+    if config["bot"]["owner_id"]:
+        owner_ids.add(config["bot"]["owner_id"])
+    else:
+        app = await client.application_info()
+        if app.team:
+            owner_ids.update(member.id for member in app.team.members)
+        else:
+            owner_ids.add(app.owner.id)
+    logger.info(f"🔑 Bot owner: {', '.join(str(user_id) for user_id in owner_ids)}")
+
+def is_owner(user_id: int) -> bool:
+    return user_id in owner_ids
+
+def owner_bypass(member) -> bool:
+    """Whether the bot owner skips a permission check; logged because it works on every server"""
+    if not is_owner(member.id):
+        return False
+    guild = getattr(member, "guild", None)
+    logger.warning(f"🔑 Owner bypass used by {member} in guild {guild.id if guild else 'DM'}")
+    return True
+
+def is_admin(member) -> bool:
+    """Administrators of the server and the bot owner"""
+    permissions = getattr(member, "guild_permissions", None)
+    return (permissions is not None and permissions.administrator) or owner_bypass(member)
+
+def has_dj_permission(member) -> bool:
+    """Members with Manage Server or the configured DJ role count as DJs, and so does the bot owner"""
+    guild = getattr(member, "guild", None)
+    if guild is None:  # Direct messages
+        return False
+    if member.guild_permissions.manage_guild:
+        return True
+    dj_role_id = guild_settings.get(guild.id, {}).get("dj_role")
+    return (dj_role_id is not None and any(role.id == dj_role_id for role in member.roles)) or owner_bypass(member)
+
+def check_dj_permission(interaction: Interaction, action: str):
+    """Raise PermissionDeniedError unless the user is a DJ"""
+    if not has_dj_permission(interaction.user):
+        raise PermissionDeniedError(interaction.user.id, action)
+
+def check_station_access(member, station_name: str, station: dict, voice_channel):
+    """
+    Raise StationRestrictedError or ListenerLimitError if the member may not start this station in the voice channel.
+    Shared by the slash command, the buttons, the player reactions and the prefix commands.
+    """
+    guild_name = member.guild.name if getattr(member, "guild", None) else "Unknown"
+    if station.get("requires_dj") and not has_dj_permission(member):
+        logger.info(f"🔒 Refused restricted station '{station_name}' for {member.display_name} in '{guild_name}'")
+        raise StationRestrictedError(station_name)
+
+    # Some stations pay per listener and cap how many may tune in at once
+    max_listeners = station.get("max_listeners")
+    listeners = len([listener for listener in voice_channel.members if not listener.bot])
+    if max_listeners and listeners >= max_listeners and not is_admin(member):
+        logger.warning(f"🚷 Refused '{station_name}' in '{guild_name}' #{voice_channel.name}: "
+                       f"{listeners} listeners, limit is {max_listeners}")
+        raise ListenerLimitError(station_name, listeners, max_listeners)
+
+def is_global_station(guild_id: int, name: str) -> bool:
+    """Whether the guild's station with this name is a global one (server stations shadow global names)"""
+    return name in RADIOS and name not in server_stations.get(guild_id, {})
+
+def check_alias_permission(interaction: Interaction, station: str):
+    """
+    DJs manage the aliases of their server's stations. Aliases of global stations apply to
+    every server and are written to config.yaml, so only the bot owner may change those.
+    """
+    check_dj_permission(interaction, "manage station aliases")
+    if is_global_station(interaction.guild_id, station) and not is_owner(interaction.user.id):
+        raise PermissionDeniedError(interaction.user.id, "manage aliases of global stations")
+
+def is_blacklisted(guild_id: int, user_id: int) -> bool:
+    """Check if a user has been blocked from controlling the bot in a guild"""
+    return user_id in guild_settings.get(guild_id, {}).get("blacklisted_users", [])
+
+async def reject_blacklisted(interaction: Interaction) -> bool:
+    """Tell blacklisted users they can't use the bot. Returns True if the interaction was rejected."""
+    if not is_blacklisted(interaction.guild_id, interaction.user.id):
+        return False
+    logger.info(f"🚫 Ignored interaction from blacklisted user {interaction.user} in guild {interaction.guild_id}")
+    error = PermissionDeniedError(interaction.user.id, "use this command")
+    await safe_send_message(interaction, content=error.description, ephemeral=True)
+    return True
+
+async def ignore_repeated_press(interaction: Interaction) -> bool:
+    """
+    Acknowledge a button pressed again by the same user within component_cooldown_ms without acting on it,
+    so e.g. spamming "Next" doesn't start several stations at once. Returns True if the press was ignored.
+    """
+    custom_id = (interaction.data or {}).get("custom_id", "")
+    if component_cooldown.hit(interaction.user.id, custom_id):
+        return False
+    logger.debug(f"Ignored repeated press of {custom_id} by {interaction.user}")
+    if not interaction.response.is_done():
+        try:
+            await interaction.response.defer()
+        except discord.HTTPException:
+            pass
+    return True
+
 def is_safe_url(url: str) -> tuple[bool, str]:
     """Validate if URL is safe for streaming"""
     import re
@@ -179,7 +1254,8 @@ def is_safe_url(url: str) -> tuple[bool, str]:
             'radio.de', 'tune.in', 'stream.live', 'icecast'
         ]
         
-        if not any(domain in url.lower() for domain in trusted_domains):
+        # YouTube live streams are played through yt-dlp
+        if not any(domain in url.lower() for domain in trusted_domains) and not is_youtube_url(url):
             return False, "URL doesn't appear to be from a recognized streaming service"
     
     return True, "URL appears safe"
@@ -191,42 +1267,136 @@ def get_available_stations(guild_id: int) -> Dict[str, Dict]:
         available.update(server_stations[guild_id])
     return available
 
+def find_station_by_alias(guild_id: int, alias: str) -> Optional[str]:
+    """Name of the station that has an alias (case-insensitive), or None"""
+    alias_lower = alias.lower().strip()
+    for name, station in get_available_stations(guild_id).items():
+        # An alias repeating the station's own name is not a separate match
+        aliases = [existing.lower() for existing in station.get("aliases") or [] if existing.lower() != name.lower()]
+        if alias_lower in aliases:
+            return name
+    return None
+
+def get_station_by_url(guild_id: int, url: Optional[str]) -> Optional[Tuple[str, Dict]]:
+    """(name, station) of the guild's station with this stream URL, e.g. after the station was renamed"""
+    return next(((name, station) for name, station in get_available_stations(guild_id).items() if station["url"] == url), None)
+
+def resolve_station_name(guild_id: int, name: str) -> Optional[str]:
+    """
+    Resolve a typed station name, or None if nothing matches.
+    Priority: exact name > name ignoring case > alias. Fuzzy name matches are never
+    played directly; /radio play only suggests them (see rank_stations).
+    """
+    stations = get_available_stations(guild_id)
+    if name in stations:
+        return name
+    name_lower = name.lower().strip()
+    match = next((station_name for station_name in stations if station_name.lower() == name_lower), None)
+    return match or find_station_by_alias(guild_id, name)
+
+def get_playlist_title(url: str) -> Optional[str]:
+    """Get the stream title announced by a resolved playlist, if any"""
+    return stream_cache.get(url, {}).get("title")
+
+def get_guild_quality(guild_id: int) -> str:
+    """Get the preferred quality tier for a guild, falling back to the global default"""
+    return guild_settings.get(guild_id, {}).get("quality") or config.get("bot", {}).get("default_quality", "high")
+
+def get_stream_source(station: Dict, guild_id: int) -> tuple[str, Optional[str]]:
+    """
+    Pick the URL to stream for a station.
+    Returns (url, quality) where quality is None if the station has no URL for the preferred tier.
+    """
+    quality = get_guild_quality(guild_id)
+    quality_url = (station.get("quality_urls") or {}).get(quality)
+    if quality_url:
+        return quality_url, quality
+    return station["url"], None
+
+def country_flag(country_code: Optional[str]) -> str:
+    """Flag emoji for an ISO 3166-1 alpha-2 code ("US" -> 🇺🇸), or "" for anything else"""
+    code = (country_code or "").strip().upper()
+    if len(code) != 2 or not code.isascii() or not code.isalpha():
+        return ""
+    return "".join(chr(0x1F1E6 + ord(letter) - ord("A")) for letter in code)
+
+def get_station_thumbnail(station: Optional[Dict]) -> Optional[str]:
+    """Get the logo URL for a station, falling back to an image from its homepage and then the configured default"""
+    if station and station.get("thumbnail"):
+        return station["thumbnail"]
+    health = health_status.get(station["url"]) if station else None
+    if health and health.thumbnail_url:
+        return health.thumbnail_url
+    return config.get("bot", {}).get("default_thumbnail")
+
 async def get_station_autocomplete(interaction: Interaction, current: str) -> List[app_commands.Choice[str]]:
     """Get autocomplete choices for station names"""
-    guild_stations = get_available_stations(interaction.guild_id)
+    # Restricted stations are only offered to DJs; checking the owner first avoids logging a bypass per keystroke
+    is_dj = is_owner(interaction.user.id) or has_dj_permission(interaction.user)
+    guild_stations = {name: station for name, station in get_available_stations(interaction.guild_id).items()
+                      if name not in disabled_stations and (is_dj or not station.get("requires_dj"))}
     
+    def choice(name: str) -> app_commands.Choice[str]:
+        # Health emoji and genre are only shown; the selected value stays the plain station name
+        genre = guild_stations[name].get("genre")
+        label = f"[{genre}] {name}" if genre else name
+        return app_commands.Choice(name=f"{get_health_emoji(guild_stations[name]['url'])} {label}"[:100], value=name)
+
     if not current:
-        # Return first 25 stations if no input
-        return [app_commands.Choice(name=name, value=name) for name in list(guild_stations.keys())[:25]]
+        # First 25 stations, grouped by genre (stations without one last)
+        ordered = sorted(guild_stations, key=lambda name: (not guild_stations[name].get("genre"),
+                                                            (guild_stations[name].get("genre") or "").lower(), name.lower()))
+        return [choice(name) for name in ordered[:25]]
     
-    # Fuzzy search for matching stations
-    matches = []
-    current_lower = current.lower()
-    
-    for station_name in guild_stations.keys():
-        if current_lower in station_name.lower():
-            matches.append(app_commands.Choice(name=station_name, value=station_name))
-        if len(matches) >= 25:  # Discord limit
-            break
-            
-    return matches
+    # Matches by name, tags or description
+    matches = [name for name in search_stations(interaction.guild_id, current, include_disabled=False) if name in guild_stations]
+    return [choice(name) for name in matches[:25]]  # Discord limit
+
+async def get_language_autocomplete(interaction: Interaction, current: str) -> List[app_commands.Choice[str]]:
+    """Languages of the guild's stations, each once and sorted"""
+    languages = sorted({station["language"] for station in get_available_stations(interaction.guild_id).values()
+                        if station.get("language")})
+    return [app_commands.Choice(name=language, value=language) for language in languages
+            if current.lower() in language.lower()][:25]
+
+async def get_country_autocomplete(interaction: Interaction, current: str) -> List[app_commands.Choice[str]]:
+    """Countries of the guild's stations, each once and sorted"""
+    countries = sorted({station["country"].upper() for station in get_available_stations(interaction.guild_id).values()
+                        if country_flag(station.get("country"))})
+    return [app_commands.Choice(name=f"{country_flag(code)} {code}", value=code)
+            for code in countries if current.upper() in code][:25]
 
 class RadioSelectMenu(ui.Select):
-    def __init__(self, guild_id: int, page: int = 0):
+    def __init__(self, guild_id: int, page: int = 0, stations: Optional[List[str]] = None):
+        """Shows one page of all stations, or the given stations (e.g. search results)"""
         self.page = page
         self.guild_id = guild_id
         available_stations = get_available_stations(guild_id)
-        stations = list(available_stations.keys())
+        # Healthy stations first, then unchecked, then failing, then disabled
+        health_order = {"🟢": 0, "🟡": 1, "🔴": 2, "⛔": 3}
+        stations = sorted(stations if stations is not None else available_stations,
+                          key=lambda name: health_order[get_station_emoji(name, available_stations[name])])
         start_idx = page * 25
         end_idx = start_idx + 25
         page_stations = stations[start_idx:end_idx]
         
         options = []
         for station in page_stations:
-            # Show if it's a server-specific station
-            desc_suffix = " (Server)" if guild_id in server_stations and station in server_stations[guild_id] else " (Global)"
-            description = f"Play {station}{desc_suffix}"[:100]
-            options.append(SelectOption(label=station[:100], description=description, value=station))
+            # Show bitrate/format and if it's a server-specific station
+            scope = "Server" if guild_id in server_stations and station in server_stations[guild_id] else "Global"
+            details = get_stream_details(available_stations[station])
+            language = available_stations[station].get("language")
+            description = " • ".join(part for part in (details, language, scope) if part)[:50]
+            emoji = get_station_emoji(station, available_stations[station])
+            label = f"{station} 🌐" if available_stations[station].get("homepage") else station
+            flag = country_flag(available_stations[station].get("country"))
+            if flag:
+                label = f"{flag} {label}"
+            # Labels are plain text, so custom emojis (<:name:id>) would show up as code
+            custom_emoji = available_stations[station].get("discord_emoji")
+            if custom_emoji and not custom_emoji.startswith("<"):
+                label = f"{custom_emoji} {label}"
+            options.append(SelectOption(label=label[:100], description=description, value=station, emoji=emoji))
         
         if not options:
             options = [SelectOption(label="No stations available", description="Add stations with /station add", value="none")]
@@ -240,14 +1410,89 @@ class RadioSelectMenu(ui.Select):
         station = self.values[0]
         await RadioCog.play_radio_static(interaction, station)
 
+class Direction(Enum):
+    PREV = -1
+    NEXT = 1
+
+def get_adjacent_station(guild_id: int, direction: Direction) -> Optional[str]:
+    """The previous/next playable station of the guild's station list (wrapping around)"""
+    names = [name for name in get_available_stations(guild_id) if name not in disabled_stations]
+    if not names:
+        return None
+    current = current_radios.get(guild_id, {}).get("name")
+    index = names.index(current) if current in names else -direction.value
+    return names[(index + direction.value) % len(names)]
+
+async def preload_station(guild_id: int, station_name: str):
+    """Resolve a station's stream URL into stream_cache ahead of time, so switching to it starts faster"""
+    station = get_available_stations(guild_id).get(station_name)
+    if station is None:
+        return
+    url, _ = get_stream_source(station, guild_id)
+    try:
+        await asyncio.wait_for(resolve_stream_url(url), timeout=PRELOAD_TIMEOUT)
+        logger.debug(f"Preloaded stream URL of '{station_name}'")
+    except (asyncio.TimeoutError, AlastorError) as e:
+        logger.debug(f"Preloading '{station_name}' failed: {e!r}")
+
+async def navigate_station(interaction: Interaction, direction: Direction):
+    """Switch to the previous/next station of the guild's station list (wrapping around)"""
+    station_name = get_adjacent_station(interaction.guild_id, direction)
+    if station_name is None:
+        await safe_send_message(interaction, content="No stations available.", ephemeral=True)
+        return
+    logger.info(f"{'⏮️' if direction is Direction.PREV else '⏭️'} {interaction.user.display_name} switched to '{station_name}'")
+    await RadioCog.play_radio_static(interaction, station_name)
+
 class StationControlView(ui.View):
     def __init__(self, guild_id: int):
         super().__init__(timeout=300)
         self.guild_id = guild_id
+
+        # Navigating would loop back to the same station
+        single_station = len(get_available_stations(guild_id)) <= 1
+        self.previous_button.disabled = single_station
+        self.next_button.disabled = single_station
+
+    async def interaction_check(self, interaction: Interaction) -> bool:
+        return not await reject_blacklisted(interaction) and not await ignore_repeated_press(interaction)
+
+    @ui.button(label="⏮️ Previous", style=discord.ButtonStyle.secondary, custom_id="player_prev")
+    async def previous_button(self, interaction: Interaction, button: ui.Button):
+        await navigate_station(interaction, Direction.PREV)
         
     @ui.button(label="⏹️ Stop", style=discord.ButtonStyle.red)
     async def stop_button(self, interaction: Interaction, button: ui.Button):
         await RadioCog.stop_radio_static(interaction)
+
+    @ui.button(label="⏭️ Next", style=discord.ButtonStyle.secondary, custom_id="player_next")
+    async def next_button(self, interaction: Interaction, button: ui.Button):
+        await navigate_station(interaction, Direction.NEXT)
+
+    @ui.button(label="🗳️ Vote Skip", style=discord.ButtonStyle.primary, custom_id="player_skip_vote")
+    async def skip_vote_button(self, interaction: Interaction, button: ui.Button):
+        radio_data = current_radios.get(self.guild_id)
+        voice_channel = getattr(radio_data["voice_client"], "channel", None) if radio_data else None
+        if voice_channel is None:
+            await safe_send_message(interaction, content="Nothing is playing right now.", ephemeral=True)
+            return
+        if interaction.user not in voice_channel.members:
+            await safe_send_message(interaction, content=f"Join {voice_channel.mention} to vote.", ephemeral=True)
+            return
+
+        votes = skip_votes.setdefault(self.guild_id, set())
+        votes.add(interaction.user.id)
+        listeners = [member for member in voice_channel.members if not member.bot]
+        needed = max(math.ceil(len(listeners) * config["bot"]["skip_vote_ratio"]), 1)
+        logger.info(f"🗳️ Skip vote by {interaction.user.display_name} in {interaction.guild.name} ({len(votes)}/{needed})")
+
+        if len(votes) >= needed:
+            skip_votes.pop(self.guild_id, None)
+            await navigate_station(interaction, Direction.NEXT)
+            return
+
+        button.label = f"🗳️ Skip ({len(votes)}/{needed})"
+        await interaction.response.edit_message(view=self)
         
     @ui.button(label="ℹ️ Info", style=discord.ButtonStyle.secondary)
     async def info_button(self, interaction: Interaction, button: ui.Button):
@@ -269,15 +1514,167 @@ class RadioListView(ui.View):
                 self.add_item(PreviousPageButton(guild_id, page))
             if page < total_pages - 1:
                 self.add_item(NextPageButton(guild_id, page))
-                
-class PreviousPageButton(ui.Button):
-    def __init__(self, guild_id: int, current_page: int):
-        super().__init__(label="◀️ Previous", style=discord.ButtonStyle.secondary)
-        self.current_page = current_page
-        self.guild_id = guild_id
-        
-    async def callback(self, interaction: Interaction):
-        new_view = RadioListView(self.guild_id, self.current_page - 1)
+            # Select menus are limited to 25 options, so offer a search as well
+            self.add_item(StationSearchButton())
+
+    async def interaction_check(self, interaction: Interaction) -> bool:
+        return not await reject_blacklisted(interaction) and not await ignore_repeated_press(interaction)
+
+def build_leaderboard_embed(guild_id: Optional[int]) -> Embed:
+    """Top 5 stations on all servers and on this server"""
+    def format_top(top: List[tuple]) -> str:
+        if not top:
+            return "No plays yet"
+        return "\n".join(f"{i}. **{name}** — {count} play{'s' if count != 1 else ''}" for i, (name, count) in enumerate(top, 1))
+
+    embed = Embed(title="🏆 Station Leaderboard", color=discord.Color.gold())
+    embed.add_field(name="🌍 Global", value=format_top(stats_tracker.global_top_stations(5)), inline=True)
+    if guild_id:
+        embed.add_field(name="🏛️ This Server", value=format_top(stats_tracker.top_stations(guild_id, 5)), inline=True)
+    embed.set_footer(text="Alastor - The Radio Daemon")
+    return embed
+
+class LeaderboardView(ui.View):
+    def __init__(self, guild_id: int):
+        super().__init__(timeout=120)
+        self.guild_id = guild_id
+
+    @ui.button(label="Reset my guild stats", style=discord.ButtonStyle.danger, emoji="🧹")
+    async def reset_button(self, interaction: Interaction, button: ui.Button):
+        if not is_admin(interaction.user):
+            await safe_send_message(interaction, content="Only server administrators can reset statistics.", ephemeral=True)
+            return
+        stats_tracker.reset(self.guild_id)
+        save_state()
+        logger.info(f"🧹 Admin {interaction.user.display_name} reset statistics in {interaction.guild.name}")
+        button.disabled = True
+        await interaction.response.edit_message(embed=build_leaderboard_embed(self.guild_id), view=self)
+
+def station_matches_language(station: Dict, language: str) -> bool:
+    """Match a language code against the station's language (a primary subtag like fr also matches fr-CA)"""
+    tag = (station.get("language") or "").lower()
+    language = language.lower().strip()
+    return bool(tag) and (tag == language or tag.split("-")[0] == language)
+
+def score_station(name: str, station: Dict, query: str) -> float:
+    """How well a station matches a lowercase query: name and alias matches beat tags, tags beat the description"""
+    name_lower = name.lower()
+    if name_lower == query:
+        return 1.0
+    aliases = [alias.lower() for alias in station.get("aliases") or []]
+    # A station can match by name and by alias at once; it counts once, with the better score
+    name_score = 0.9 if query in name_lower else 0.0
+    alias_score = 0.95 if query in aliases else 0.85 if any(query in alias for alias in aliases) else 0.0
+    if name_score or alias_score:
+        return max(name_score, alias_score)
+    tags = station.get("tags") or []
+    if isinstance(tags, str):
+        tags = tags.split(",")
+    if any(query in tag.lower() for tag in tags + [station.get("genre") or ""]):
+        return 0.75
+    if query in (station.get("description") or "").lower():
+        return 0.65
+    if station_matches_language(station, query):
+        return 0.6
+    return 0.0
+
+def search_stations(guild_id: int, query: str, include_disabled: bool = True) -> List[str]:
+    """
+    Station names matching a query by name, alias, tags, description or language code, best matches first.
+    A leading "genre:<genre>" only keeps stations with exactly that genre before scoring the rest of the query.
+    """
+    stations = {
+        name: station for name, station in get_available_stations(guild_id).items()
+        if include_disabled or name not in disabled_stations
+    }
+    query_lower = query.lower().strip()
+    if query_lower.startswith("genre:"):
+        genre, _, query_lower = query_lower[len("genre:"):].strip().partition(" ")
+        stations = {name: station for name, station in stations.items() if (station.get("genre") or "").lower() == genre}
+        query_lower = query_lower.strip()
+        if not query_lower:
+            return list(stations)
+
+    # Keyed by station name, so every station is listed at most once
+    scores = {name: score_station(name, station, query_lower) for name, station in stations.items()}
+    # sorted() is stable, so equal scores keep the configured station order
+    return sorted((name for name, score in scores.items() if score > 0), key=lambda name: -scores[name])
+
+SUGGESTION_MIN_SCORE = 0.5  # Below this, a station is too different to be what the user meant
+
+def rank_stations(guild_id: int, query: str, top_n: int = 3) -> List[Tuple[str, float]]:
+    """
+    The top_n enabled stations closest to a (possibly misspelled) name, with their similarity (0-1), best first.
+    Uses the better of the search score and the spelling similarity to the name or an alias.
+    """
+    query_lower = query.lower().strip()
+    ranked = []
+    for name, station in get_available_stations(guild_id).items():
+        if name in disabled_stations:
+            continue
+        spellings = [name.lower()] + [alias.lower() for alias in station.get("aliases") or []]
+        similarity = max(difflib.SequenceMatcher(None, query_lower, spelling).ratio() for spelling in spellings)
+        score = max(similarity, score_station(name, station, query_lower))
+        if score >= SUGGESTION_MIN_SCORE:
+            ranked.append((name, score))
+    return sorted(ranked, key=lambda entry: -entry[1])[:top_n]
+
+class StationSuggestionButton(ui.Button):
+    def __init__(self, station_name: str):
+        super().__init__(label=station_name[:80], style=discord.ButtonStyle.primary, emoji="▶", custom_id=f"retry_{station_name}"[:100])
+        self.station_name = station_name
+
+    async def callback(self, interaction: Interaction):
+        await RadioCog.play_radio_static(interaction, self.station_name)
+
+class StationSuggestionView(ui.View):
+    """Buttons for the "Did you mean" suggestions, so a suggested station plays without retyping it"""
+    def __init__(self, station_names: List[str]):
+        super().__init__(timeout=120)
+        for name in station_names:
+            self.add_item(StationSuggestionButton(name))
+
+    async def interaction_check(self, interaction: Interaction) -> bool:
+        return not await reject_blacklisted(interaction) and not await ignore_repeated_press(interaction)
+
+class StationSearchModal(ui.Modal, title="Search Stations"):
+    query = ui.TextInput(label="Station name or genre", placeholder="e.g. jazz", max_length=100)
+
+    def __init__(self):
+        super().__init__(custom_id="station_search_modal")
+
+    async def on_submit(self, interaction: Interaction):
+        matches = search_stations(interaction.guild_id, self.query.value)
+        if not matches:
+            await safe_send_message(interaction, content=f"No stations match **{self.query.value}**.", ephemeral=True)
+            return
+
+        view = ui.View(timeout=120)
+        view.add_item(RadioSelectMenu(interaction.guild_id, stations=matches[:25]))
+        embed = Embed(
+            title="🔍 Search Results",
+            description=f"**{len(matches)}** station{'s' if len(matches) != 1 else ''} match **{self.query.value}**"
+                        + (" (showing the first 25)" if len(matches) > 25 else "") + ":",
+            color=discord.Color.blue()
+        )
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await safe_send_message(interaction, embed=embed, view=view, ephemeral=True)
+
+class StationSearchButton(ui.Button):
+    def __init__(self):
+        super().__init__(label="🔍 Search", style=discord.ButtonStyle.primary)
+
+    async def callback(self, interaction: Interaction):
+        await interaction.response.send_modal(StationSearchModal())
+                
+class PreviousPageButton(ui.Button):
+    def __init__(self, guild_id: int, current_page: int):
+        super().__init__(label="◀️ Previous", style=discord.ButtonStyle.secondary)
+        self.current_page = current_page
+        self.guild_id = guild_id
+        
+    async def callback(self, interaction: Interaction):
+        new_view = RadioListView(self.guild_id, self.current_page - 1)
         available_stations = get_available_stations(self.guild_id)
         global_count = len(RADIOS)
         server_count = len(server_stations.get(self.guild_id, {}))
@@ -308,332 +1705,917 @@ class NextPageButton(ui.Button):
         embed.set_footer(text="Alastor - The Radio Daemon")
         await interaction.response.edit_message(embed=embed, view=new_view)
 
-class RadioCog(commands.Cog):
-    def __init__(self, bot: commands.Bot):
-        self.bot = bot
+def station_matches_genre(station: Dict, genre: str) -> bool:
+    """Match a genre against a station's genre, tags (list or comma-separated) and description"""
+    genre = genre.lower().strip()
+    tags = station.get("tags") or []
+    if isinstance(tags, str):
+        tags = tags.split(",")
+    if any(genre in tag.lower() for tag in tags + [station.get("genre") or ""]):
+        return True
+    return genre in (station.get("description") or "").lower()
 
-    # Define an app_commands.Group; all methods decorated with @radio.command will be registered as /radio <subcommand>
-    radio = app_commands.Group(name="radio", description="Manage radio stations")
+def build_genre_lines(stations: Dict[str, Dict], max_length: int = 2000) -> List[str]:
+    """A bold genre header followed by its stations, for stations with a genre set"""
+    by_genre: Dict[str, List[str]] = {}
+    for name, station in stations.items():
+        if station.get("genre"):
+            by_genre.setdefault(station["genre"], []).append(name)
+    lines, length = [], 0
+    for genre in sorted(by_genre, key=str.lower):
+        names = sorted(by_genre[genre], key=str.lower)
+        line = f"**{genre}**\n{', '.join(f'{get_discord_emoji(stations[name])} {name}' for name in names)}"
+        if length + len(line) > max_length:
+            lines.append("…")
+            break
+        lines.append(line)
+        length += len(line)
+    return lines
 
-    @staticmethod
-    async def play_radio_static(interaction: Interaction, station_name: str, show_loading: bool = True):
-        """Enhanced radio playback with better feedback and multi-server support"""
-        # Get guild_id first
-        guild_id = interaction.guild_id
-        
-        # Log the play request
-        guild_name = interaction.guild.name if interaction.guild else "Unknown"
-        user_name = f"{interaction.user.display_name} ({interaction.user.name})"
-        logger.info(f"🎵 Play request: '{station_name}' by {user_name} in '{guild_name}'")
-        
-        # Show loading indicator
-        if show_loading:
-            loading_embed = Embed(
-                title="🔄 Loading...",
-                description=f"Connecting to **{station_name}**...",
-                color=discord.Color.orange()
-            )
-            loading_embed.set_footer(text="Alastor - The Radio Daemon")
-            await safe_send_message(interaction, embed=loading_embed, ephemeral=False)
-        available_stations = get_available_stations(guild_id)
-        if station_name not in available_stations:
-            embed = Embed(
-                title="❌ Station Not Found",
-                description=f"Station **{station_name}** does not exist.\n\nUse `/radio list` to see available stations or `/station add` to add new ones.",
-                color=discord.Color.red()
-            )
-            embed.set_footer(text="Alastor - The Radio Daemon")
-            await safe_send_message(interaction, embed=embed, ephemeral=True)
-            return
+def pick_random_station(guild_id: int, genre: Optional[str] = None) -> Optional[str]:
+    """Pick a random station, preferring ones that passed their last health check"""
+    stations = {name: station for name, station in get_available_stations(guild_id).items() if name not in disabled_stations}
+    if genre:
+        stations = {name: station for name, station in stations.items() if station_matches_genre(station, genre)}
+    if not stations:
+        return None
+    healthy = [name for name, station in stations.items() if getattr(health_status.get(station["url"]), "is_healthy", False)]
+    # Without any known healthy station, pick from all of them
+    return random.choice(healthy or list(stations))
 
-        voice_channel = getattr(interaction.user.voice, "channel", None)
-        if not voice_channel:
-            embed = Embed(
-                title="Error",
-                description="You must be in a voice channel to play radio.",
-                color=discord.Color.red()
-            )
-            embed.set_footer(text="Alastor - The Radio Daemon")
-            await safe_send_message(interaction, embed=embed, ephemeral=True)
+def build_health_lines(guild_id: int) -> List[str]:
+    """One line per station: failing first, then unchecked, then healthy"""
+    entries = []
+    for name, station in get_available_stations(guild_id).items():
+        health = health_status.get(station["url"])
+        if health is None:
+            entries.append((1, name, f"🟡 {name} | not checked yet"))
+            continue
+        details = get_stream_details(station)
+        line = f"{get_health_emoji(station['url'])} {name} | last checked {format_ago(time.time() - health.last_checked)}"
+        if details:
+            line += f" | {details}"
+        if not health.is_healthy:
+            line += f" | {health.message[:60]}"
+        if health.homepage_reachable is False:
+            line += " | website unreachable"
+        entries.append((2 if health.is_healthy else 0, name, line))
+    return [line for _, _, line in sorted(entries, key=lambda entry: (entry[0], entry[1].lower()))]
+
+HEALTH_PAGE_SIZE = 15
+
+def build_health_embed(guild_id: int, page: int) -> Embed:
+    lines = build_health_lines(guild_id)
+    total_pages = max((len(lines) + HEALTH_PAGE_SIZE - 1) // HEALTH_PAGE_SIZE, 1)
+    page_lines = lines[page * HEALTH_PAGE_SIZE:(page + 1) * HEALTH_PAGE_SIZE]
+    healthy = sum(1 for line in lines if line.startswith("🟢"))
+
+    embed = Embed(
+        title="🩺 Station Health",
+        description="\n".join(page_lines) or "No stations configured.",
+        color=discord.Color.green() if healthy == len(lines) else discord.Color.orange()
+    )
+    embed.add_field(name="Summary", value=f"{healthy} of {len(lines)} stations healthy", inline=False)
+    embed.set_footer(text=f"Alastor - The Radio Daemon • Page {page + 1} of {total_pages}")
+    return embed
+
+class HealthReportView(ui.View):
+    def __init__(self, guild_id: int, page: int = 0):
+        super().__init__(timeout=120)
+        self.guild_id = guild_id
+        self.page = page
+        total_pages = (len(get_available_stations(guild_id)) + HEALTH_PAGE_SIZE - 1) // HEALTH_PAGE_SIZE
+        self.previous_page.disabled = page == 0
+        self.next_page.disabled = page >= total_pages - 1
+
+    @ui.button(label="◀️ Previous", style=discord.ButtonStyle.secondary)
+    async def previous_page(self, interaction: Interaction, button: ui.Button):
+        await interaction.response.edit_message(
+            embed=build_health_embed(self.guild_id, self.page - 1),
+            view=HealthReportView(self.guild_id, self.page - 1)
+        )
+
+    @ui.button(label="Next ▶️", style=discord.ButtonStyle.secondary)
+    async def next_page(self, interaction: Interaction, button: ui.Button):
+        await interaction.response.edit_message(
+            embed=build_health_embed(self.guild_id, self.page + 1),
+            view=HealthReportView(self.guild_id, self.page + 1)
+        )
+
+class StationOfTheDayView(ui.View):
+    def __init__(self, station_name: str):
+        super().__init__(timeout=None)
+        self.station_name = station_name
+
+    async def interaction_check(self, interaction: Interaction) -> bool:
+        return not await reject_blacklisted(interaction) and not await ignore_repeated_press(interaction)
+
+    @ui.button(label="▶ Play", style=discord.ButtonStyle.green)
+    async def play_button(self, interaction: Interaction, button: ui.Button):
+        await RadioCog.play_radio_static(interaction, self.station_name)
+
+async def announce_station_of_the_day(client, guild_id: int) -> bool:
+    """Post a random station to the guild's station-of-the-day channel. Returns True if posted."""
+    settings = guild_settings.get(guild_id, {})
+    channel = client.get_channel(settings.get("sotd_channel") or 0)
+    station_name = pick_random_station(guild_id)
+    if channel is None or station_name is None:
+        return False
+
+    station = get_available_stations(guild_id)[station_name]
+    embed = Embed(
+        title="🌟 Station of the Day",
+        description=f"**{station_name}**" + (f"\n\n{station['description']}" if station.get("description") else ""),
+        color=discord.Color.gold()
+    )
+    thumbnail = get_station_thumbnail(station)
+    if thumbnail:
+        embed.set_thumbnail(url=thumbnail)
+    embed.set_footer(text="Alastor - The Radio Daemon")
+    try:
+        await channel.send(embed=embed, view=StationOfTheDayView(station_name))
+    except discord.HTTPException as e:
+        logger.error(f"❌ Failed to post station of the day in guild {guild_id}: {e}")
+        return False
+    logger.info(f"🌟 Station of the day for guild {guild_id}: '{station_name}'")
+    return True
+
+class WatchedSource(discord.AudioSource):
+    """Wraps an audio source and counts the frames sent to Discord"""
+
+    def __init__(self, source: discord.AudioSource):
+        self.source = source
+        self.frames = 0
+
+    def read(self) -> bytes:
+        data = self.source.read()
+        if data:
+            self.frames += 1
+        return data
+
+    def is_opus(self) -> bool:
+        return self.source.is_opus()
+
+    def cleanup(self):
+        self.source.cleanup()
+
+class StreamWatchdog:
+    """
+    Detects streams that stay connected but silently stop delivering audio:
+    if the player is still playing but no new frames were read since the last check,
+    the station is restarted.
+    """
+
+    def __init__(self, client, guild_id: int, source: WatchedSource, interval: int = 30):
+        self.client = client
+        self.guild_id = guild_id
+        self.source = source
+        self.interval = interval
+        self.last_frames = 0
+        self.task: Optional[asyncio.Task] = None
+
+    def start(self):
+        self.task = asyncio.create_task(self._run())
+
+    def stop(self):
+        # The watchdog stops itself when restarting the station; don't cancel that restart
+        if self.task and not self.task.done() and self.task is not asyncio.current_task():
+            self.task.cancel()
+
+    async def _run(self):
+        radio_data = current_radios.get(self.guild_id)
+        while True:
+            await asyncio.sleep(self.interval)
+            if current_radios.get(self.guild_id) is not radio_data:
+                return  # Station changed or stopped
+            voice_client = radio_data["voice_client"]
+            frames = self.source.frames
+            if voice_client.is_playing() and frames == self.last_frames:
+                await self._restart(radio_data)
+                return
+            self.last_frames = frames
+
+    async def _restart(self, radio_data: Dict):
+        station_name = radio_data["name"]
+        guild = self.client.get_guild(self.guild_id)
+        voice_channel = guild.get_channel(radio_data["channel_id"]) if guild else None
+        logger.warning(f"🐕 No audio from '{station_name}' for {self.interval}s, reconnecting")
+        await notify_stream_failure(station_name, radio_data["url"], self.guild_id, "Stream stopped delivering audio")
+        if voice_channel is None:
             return
+        try:
+            await restart_station(self.client, guild, voice_channel, radio_data)
+        except Exception as e:
+            logger.error(f"❌ Reconnecting '{station_name}' failed: {e}")
+            await stop_station(self.client, self.guild_id)
 
-        guild_id = interaction.guild_id
-        voice_client = discord.utils.get(interaction.client.voice_clients, guild=interaction.guild)
+def get_retry_delay(attempt: int) -> float:
+    """Seconds to wait before reconnect attempt 1, 2, ... (exponential backoff, capped at retry_max_delay_ms)"""
+    bot_config = config["bot"]
+    delay_ms = bot_config["retry_base_delay_ms"] * bot_config["retry_backoff_multiplier"] ** (attempt - 1)
+    return min(delay_ms, bot_config["retry_max_delay_ms"]) / 1000
 
-        # Stop any currently playing stream
-        if guild_id in current_radios:
-            current_vc = current_radios[guild_id]["voice_client"]
-            current_vc.stop()
-
-        # Always clean up any existing voice clients first
-        logger.info(f"🔊 Connecting to voice channel: {voice_channel.name}")
-        logger.info(f"🌍 Server: {interaction.guild.name} (ID: {interaction.guild_id})")
-        logger.info(f"🔊 Channel: {voice_channel.name} (ID: {voice_channel.id})")
-        logger.info(f"🌐 Voice region: {getattr(interaction.guild, 'region', 'Unknown')}")
-        logger.info(f"👤 Bot permissions: {voice_channel.permissions_for(interaction.guild.me)}")
+async def report_station_gone(client, radio_data: Dict):
+    """Tell the channel the station was started from that it can't be restarted"""
+    channel = client.get_channel(radio_data.get("text_channel_id") or 0)
+    if channel is None:
+        return
+    embed = Embed(
+        title="❌ Station No Longer Available",
+        description=f"**{radio_data['name']}** was removed from the station list, so its stream can't be restarted.",
+        color=discord.Color.red()
+    )
+    embed.set_footer(text="Alastor - The Radio Daemon")
+    try:
+        await channel.send(embed=embed)
+    except discord.HTTPException as e:
+        logger.warning(f"⚠️ Could not report removed station in #{channel}: {e}")
+
+async def restart_station(client, guild, voice_channel, radio_data: Dict) -> Optional[Dict]:
+    """
+    Start the playing station again (loop mode, watchdog), following renames by its stream URL.
+    If the station was removed from the config, the channel is told and playback stops; returns None then.
+    """
+    station_name = radio_data["name"]
+    # The config may have changed since the station started
+    if station_name not in get_available_stations(guild.id):
+        match = get_station_by_url(guild.id, radio_data.get("station_url"))
+        if match is None:
+            logger.warning(f"🔁 '{station_name}' no longer exists, not restarting it")
+            await report_station_gone(client, radio_data)
+            await stop_station(client, guild.id)
+            return None
+        logger.info(f"🔁 '{station_name}' was renamed to '{match[0]}'")
+        station_name = match[0]
+    new_data = await start_station(client, guild, voice_channel, station_name, radio_data.get("requested_by"))
+    new_data["text_channel_id"] = radio_data.get("text_channel_id")
+    return new_data
+
+async def replay_station(client, guild_id: int, radio_data: Dict):
+    """
+    Loop mode: restart a station whose stream ended by itself.
+    Streams that keep ending shortly after starting are restarted with exponential backoff.
+    """
+    station_name = radio_data["name"]
+    max_retries = config["bot"]["max_stream_retries"]
+    played_for = time.time() - radio_data.get("start_time", time.time())
+    restarts = 0 if played_for >= LOOP_STABLE_AFTER else radio_data.get("loop_restarts", 0) + 1
+    if restarts > max_retries:
+        logger.error(f"🔁 '{station_name}' keeps ending, giving up after {max_retries} restarts")
+        await notify_stream_failure(station_name, radio_data["url"], guild_id, "Stream keeps ending in loop mode")
+        await stop_station(client, guild_id)
+        return
+
+    delay = get_retry_delay(restarts) if restarts else 0
+    logger.info(f"🔁 Stream of '{station_name}' ended, restarting in {delay:.1f}s (loop mode, attempt {restarts}/{max_retries})")
+    await asyncio.sleep(delay)
+    # Stopped, switched or loop turned off in the meantime
+    if current_radios.get(guild_id) is not radio_data or not is_looping(guild_id):
+        return
+
+    guild = client.get_guild(guild_id)
+    voice_channel = guild.get_channel(radio_data["channel_id"]) if guild else None
+    if voice_channel is None:
+        await stop_station(client, guild_id)
+        return
+    try:
+        new_data = await restart_station(client, guild, voice_channel, radio_data)
+        if new_data is not None:
+            new_data["loop_restarts"] = restarts
+    except Exception as e:
+        logger.error(f"❌ Restarting '{station_name}' in loop mode failed: {e}")
+        await stop_station(client, guild_id)
+
+def check_voice_permissions(guild, voice_channel):
+    """Raise InsufficientPermissionsError unless the bot may connect and speak in the channel"""
+    # Joining without Connect/Speak fails with a cryptic error
+    permissions = voice_channel.permissions_for(guild.me)
+    missing = [name for name, granted in (("Connect", permissions.connect), ("Speak", permissions.speak)) if not granted]
+    if missing:
+        logger.warning(f"🔒 Missing {', '.join(missing)} permission in {voice_channel.name} ({guild.name})")
+        raise InsufficientPermissionsError(voice_channel.id, missing)
+
+async def connect_voice(client, guild, voice_channel):
+    """
+    Join a voice channel, replacing any existing connection in the guild.
+    Raises VoiceConnectionError with a user-facing explanation on failure.
+    """
+    # Always clean up any existing voice clients first
+    logger.info(f"🔊 Connecting to voice channel: {voice_channel.name}")
+    logger.info(f"🌍 Server: {guild.name} (ID: {guild.id})")
+    logger.info(f"🔊 Channel: {voice_channel.name} (ID: {voice_channel.id})")
+    logger.info(f"🌐 Voice region: {getattr(guild, 'region', 'Unknown')}")
+    logger.info(f"👤 Bot permissions: {voice_channel.permissions_for(guild.me)}")
+    
+    try:
+        # Force cleanup of any existing voice connections for this guild
+        existing_vcs = [vc for vc in client.voice_clients if vc.guild.id == guild.id]
+        for vc in existing_vcs:
+            try:
+                if vc.is_connected():
+                    vc.stop()
+                    await vc.disconnect(force=True)
+                    logger.info("🔄 Cleaned up existing voice connection")
+            except Exception as cleanup_error:
+                logger.warning(f"⚠️ Error during cleanup: {cleanup_error}")
         
-        try:
-            # Force cleanup of any existing voice connections for this guild
-            existing_vcs = [vc for vc in interaction.client.voice_clients if vc.guild.id == interaction.guild_id]
-            for vc in existing_vcs:
-                try:
-                    if vc.is_connected():
-                        vc.stop()
-                        await vc.disconnect(force=True)
-                        logger.info("🔄 Cleaned up existing voice connection")
-                except Exception as cleanup_error:
-                    logger.warning(f"⚠️ Error during cleanup: {cleanup_error}")
-            
-            # Wait for cleanup to complete
-            await asyncio.sleep(1.5)
-            
-            # Connect with retry logic
-            voice_client = None
-            for attempt in range(3):
-                try:
-                    logger.info(f"🔄 Connection attempt {attempt + 1}/3")
-                    voice_client = await asyncio.wait_for(voice_channel.connect(reconnect=False, timeout=60.0), timeout=20.0)
-                    logger.info(f"✅ Successfully connected to {voice_channel.name} (attempt {attempt + 1})")
-                    break
-                except discord.errors.ConnectionClosed as e:
-                    logger.warning(f"🔄 Connection closed (attempt {attempt + 1}): {e}")
-                    # Log the voice endpoint for debugging
-                    logger.warning(f"🌐 Failed endpoint: {getattr(e, 'endpoint', 'Unknown')}")
-                    if attempt < 2:  # Not the last attempt
-                        await asyncio.sleep(5)  # Wait even longer before retry
-                        continue
-                    else:
-                        raise
-                except discord.errors.ClientException as e:
-                    if "Already connected" in str(e):
-                        logger.warning(f"🔄 Bot thinks it's connected, forcing cleanup (attempt {attempt + 1})")
-                        # Force more aggressive cleanup
-                        for vc in interaction.client.voice_clients:
-                            if vc.guild.id == interaction.guild_id:
-                                try:
-                                    await vc.disconnect(force=True)
-                                except:
-                                    pass
-                        await asyncio.sleep(2)
-                        if attempt < 2:
-                            continue
-                        else:
-                            raise
-                    else:
-                        raise
-                except asyncio.TimeoutError:
-                    logger.warning(f"⏰ Connection timeout (attempt {attempt + 1})")
-                    if attempt < 2:  # Not the last attempt
-                        await asyncio.sleep(3)  # Wait before retry
+        # Wait for cleanup to complete
+        await asyncio.sleep(1.5)
+        
+        # Connect with retry logic
+        voice_client = None
+        for attempt in range(3):
+            try:
+                logger.info(f"🔄 Connection attempt {attempt + 1}/3")
+                voice_client = await asyncio.wait_for(voice_channel.connect(reconnect=False, timeout=60.0), timeout=20.0)
+                logger.info(f"✅ Successfully connected to {voice_channel.name} (attempt {attempt + 1})")
+                break
+            except discord.errors.ConnectionClosed as e:
+                logger.warning(f"🔄 Connection closed (attempt {attempt + 1}): {e}")
+                # Log the voice endpoint for debugging
+                logger.warning(f"🌐 Failed endpoint: {getattr(e, 'endpoint', 'Unknown')}")
+                if attempt < 2:  # Not the last attempt
+                    await asyncio.sleep(5)  # Wait even longer before retry
+                    continue
+                else:
+                    raise
+            except discord.errors.ClientException as e:
+                if "Already connected" in str(e):
+                    logger.warning(f"🔄 Bot thinks it's connected, forcing cleanup (attempt {attempt + 1})")
+                    # Force more aggressive cleanup
+                    for vc in client.voice_clients:
+                        if vc.guild.id == guild.id:
+                            try:
+                                await vc.disconnect(force=True)
+                            except:
+                                pass
+                    await asyncio.sleep(2)
+                    if attempt < 2:
                         continue
                     else:
                         raise
-            
-            if not voice_client:
-                raise Exception("Failed to establish voice connection after 3 attempts")
-                        
-        except asyncio.TimeoutError:
-            logger.error(f"⏰ Connection timeout to {voice_channel.name}")
-            embed = Embed(
-                title="❌ Connection Timeout",
-                description="Failed to connect to the voice channel. This might be due to:\n• Discord server issues\n• Network connectivity problems\n• Bot token permissions\n\nPlease try again in a moment.",
-                color=discord.Color.red()
+                else:
+                    raise
+            except asyncio.TimeoutError:
+                logger.warning(f"⏰ Connection timeout (attempt {attempt + 1})")
+                if attempt < 2:  # Not the last attempt
+                    await asyncio.sleep(3)  # Wait before retry
+                    continue
+                else:
+                    raise
+        
+        if not voice_client:
+            raise Exception("Failed to establish voice connection after 3 attempts")
+                    
+    except asyncio.TimeoutError:
+        logger.error(f"⏰ Connection timeout to {voice_channel.name}")
+        raise VoiceConnectionError(
+            "Failed to connect to the voice channel. This might be due to:\n• Discord server issues\n• Network connectivity problems\n• Bot token permissions\n\nPlease try again in a moment.",
+            title="❌ Connection Timeout"
+        )
+    except discord.errors.ConnectionClosed as e:
+        logger.error(f"❌ Voice connection closed: {e}")
+        raise VoiceConnectionError(
+            "Discord voice connection was closed. This usually indicates:\n• Bot permissions issue\n• Invalid bot token\n• Discord API problems\n\nCheck bot permissions and try again.",
+            title="❌ Connection Failed"
+        )
+    except discord.errors.ClientException as e:
+        if "Already connected" in str(e):
+            logger.error(f"❌ Persistent connection conflict: {e}")
+            raise VoiceConnectionError(
+                "The bot is stuck in a connection state. This usually resolves itself in a few moments.\n\nPlease wait 30 seconds and try again, or restart the bot.",
+                title="❌ Connection Conflict"
             )
-            embed.set_footer(text="Alastor - The Radio Daemon")
-            await safe_send_message(interaction, embed=embed, ephemeral=True)
-            return
-        except discord.errors.ConnectionClosed as e:
-            logger.error(f"❌ Voice connection closed: {e}")
-            embed = Embed(
-                title="❌ Connection Failed",
-                description="Discord voice connection was closed. This usually indicates:\n• Bot permissions issue\n• Invalid bot token\n• Discord API problems\n\nCheck bot permissions and try again.",
-                color=discord.Color.red()
+        logger.error(f"❌ Voice client error: {e}")
+        raise VoiceConnectionError(
+            f"Discord client error: {str(e)[:200]}{'...' if len(str(e)) > 200 else ''}",
+            title="❌ Voice Client Error"
+        )
+    except Exception as e:
+        logger.error(f"❌ Voice connection error: {e}")
+        raise VoiceConnectionError(
+            f"Error joining voice channel: {str(e)[:200]}{'...' if len(str(e)) > 200 else ''}"
+        )
+    
+    # If we reach here, we have a successful connection
+    # Check if we need to move to a different channel
+    if voice_client.is_connected() and voice_client.channel.id != voice_channel.id:
+        logger.info(f"🔄 Moving from {voice_client.channel.name} to {voice_channel.name}")
+        try:
+            await voice_client.move_to(voice_channel)
+            logger.info(f"✅ Successfully moved to {voice_channel.name}")
+        except Exception as e:
+            logger.error(f"❌ Voice move error: {e}")
+            raise VoiceConnectionError(f"Error moving to voice channel: {e}", title="❌ Move Error")
+
+    return voice_client
+
+async def open_stream(station_name: str, urls: List[str]) -> Tuple[str, str]:
+    """
+    Resolve and test the first reachable URL: the station's stream, then its retry_urls mirrors.
+    Mirrors carry the same program, so switching to one isn't shown to the user.
+    Returns (station URL, resolved stream URL); raises the last URL's error (e.g. StreamUnavailableError) if none works.
+    """
+    error: Optional[AlastorError] = None
+    for attempt, url in enumerate(urls):
+        if attempt:
+            logger.info(f"🔁 Trying mirror {attempt} of '{station_name}': {url[:60]}...")
+        else:
+            logger.info(f"🔗 Resolving stream URL for '{station_name}': {url[:60]}...")
+        try:
+            resolved_url = await resolve_stream_url(url)
+        except AlastorError as e:
+            # E.g. a broken playlist, or a YouTube URL without yt-dlp; a mirror may still work
+            logger.error(f"❌ Failed to resolve stream URL for '{station_name}': {e.description}")
+            error = e
+            continue
+        if resolved_url is None:
+            logger.error(f"❌ Failed to resolve stream URL for '{station_name}'")
+            error = StreamUnavailableError(
+                f"Failed to retrieve stream URL for **{station_name}**.\n\nThe station may be offline or the URL is invalid.",
+                title="❌ Stream Error"
             )
-            embed.set_footer(text="Alastor - The Radio Daemon")
-            await safe_send_message(interaction, embed=embed, ephemeral=True)
-            return
-        except discord.errors.ClientException as e:
-            if "Already connected" in str(e):
-                logger.error(f"❌ Persistent connection conflict: {e}")
-                embed = Embed(
-                    title="❌ Connection Conflict",
-                    description="The bot is stuck in a connection state. This usually resolves itself in a few moments.\n\nPlease wait 30 seconds and try again, or restart the bot.",
-                    color=discord.Color.red()
-                )
+            continue
+        logger.info(f"✅ Stream URL resolved successfully for '{station_name}'")
+        logger.info(f"🔗 Final stream URL: {resolved_url}")
+
+        # Test the URL first with a simple HTTP request
+        is_healthy, health_error = await get_cached_health(url, station_name)
+        if not is_healthy:
+            logger.error(f"❌ Stream connectivity test failed for '{station_name}': {health_error}")
+            error = StreamUnavailableError(f"**{station_name}** is currently offline or unreachable.\n\nError: {health_error}")
+            continue
+        return url, resolved_url
+    raise error
+
+@instrument()
+async def start_station(client, guild, voice_channel, station_name: str, requested_by: Optional[int] = None,
+                        on_joined: Optional[Callable[[], Awaitable[None]]] = None) -> Dict:
+    """
+    Join a voice channel and start streaming a station.
+    on_joined is awaited once the voice connection is up, before the stream is resolved.
+    Returns the new current_radios entry; raises AlastorError with a user-facing message on failure.
+    """
+    guild_id = guild.id
+    station = get_available_stations(guild_id)[station_name]
+    if station_name in disabled_stations:
+        raise StationDisabledError(station_name)
+    # Check before stopping the current stream, so it keeps playing if we can't join
+    check_voice_permissions(guild, voice_channel)
+
+    # Stop any currently playing stream
+    if guild_id in current_radios:
+        previous = current_radios.pop(guild_id)
+        previous["voice_client"].stop()
+        stop_stream_tasks(guild_id, previous)
+        record_session(guild_id, previous)
+
+    voice_client = await connect_voice(client, guild, voice_channel)
+    if on_joined:
+        await on_joined()
+
+    source_url, quality = get_stream_source(station, guild_id)
+    original_url, resolved_url = await open_stream(station_name, [source_url] + list(station.get("retry_urls") or []))
+
+    # Start playing the stream with improved FFmpeg options
+    logger.info(f"🎵 Starting playback of '{station_name}'")
+    
+    # Try different FFmpeg configurations and sources
+    ffmpeg_path = "/opt/homebrew/bin/ffmpeg"  # Explicit path for macOS Homebrew
+    input_options = get_stream_input_options(station)
+    
+    # Try different approaches
+    approaches = [
+        {
+            "name": "FFmpegOpusAudio (recommended for Discord)",
+            "source_type": "opus",
+            "before_options": f"-reconnect 1 -reconnect_streamed 1 -user_agent 'Mozilla/5.0' {input_options}".strip(),
+            "options": "-vn"
+        },
+        {
+            "name": "FFmpegPCMAudio with explicit path",
+            "source_type": "pcm",
+            "executable": ffmpeg_path,
+            "before_options": f"-reconnect 1 -user_agent 'Mozilla/5.0' {input_options}".strip(),
+            "options": "-vn -f s16le -ar 48000 -ac 2"
+        },
+        {
+            "name": "FFmpegPCMAudio basic",
+            "source_type": "pcm",
+            "before_options": input_options or None,
+            "options": "-vn"
+        }
+    ]
+    
+    loop = asyncio.get_running_loop()
+    radio_data: Optional[Dict] = None  # Set once playback started
+
+    def playback_ended(error):
+        # Called from the audio player thread
+        if error:
+            logger.error(f"❌ Player error for '{station_name}': {error}")
+            asyncio.run_coroutine_threadsafe(
+                notify_stream_failure(station_name, original_url, guild_id, str(error)), loop
+            )
+        else:
+            logger.info(f"⏹️ Playback ended for '{station_name}'")
+        # Only streams that ended by themselves; stopping or switching removes the entry first
+        if radio_data is not None and current_radios.get(guild_id) is radio_data and is_looping(guild_id):
+            asyncio.run_coroutine_threadsafe(replay_station(client, guild_id, radio_data), loop)
+
+    for approach in approaches:
+        try:
+            logger.info(f"🔧 Trying {approach['name']} for '{station_name}'")
+            
+            # Prepare kwargs
+            kwargs = {k: v for k, v in approach.items() if k not in ['name', 'source_type']}
+            
+            # Create appropriate source
+            if approach['source_type'] == 'opus':
+                source = discord.FFmpegOpusAudio(resolved_url, **kwargs)
             else:
-                logger.error(f"❌ Voice client error: {e}")
-                embed = Embed(
-                    title="❌ Voice Client Error",
-                    description=f"Discord client error: {str(e)[:200]}{'...' if len(str(e)) > 200 else ''}",
-                    color=discord.Color.red()
+                source = discord.FFmpegPCMAudio(resolved_url, **kwargs)
+            
+            # Start playing (frame counting lets the watchdog detect silent drops)
+            source = WatchedSource(source)
+            voice_client.play(source, after=playback_ended)
+            logger.info(f"✅ Successfully started playing '{station_name}' with {approach['name']}")
+            break
+            
+        except Exception as e:
+            logger.error(f"❌ {approach['name']} failed for '{station_name}': {str(e) or 'Unknown error'}")
+            
+            # If this was the last attempt, show error
+            if approach == approaches[-1]:
+                raise StreamUnavailableError(
+                    f"Could not play **{station_name}**.\n\nThis might be due to:\n• FFmpeg compatibility issues on macOS\n• Stream format not supported\n• Network connectivity problems\n\nError: {str(e)[:150]}{'...' if len(str(e)) > 150 else ''}",
+                    title="❌ Playback Error"
                 )
-            embed.set_footer(text="Alastor - The Radio Daemon")
-            await safe_send_message(interaction, embed=embed, ephemeral=True)
-            return
+
+    # Enhanced state tracking
+    current_radios[guild_id] = {
+        "name": station_name, 
+        "voice_client": voice_client,
+        "url": resolved_url,
+        "station_url": station["url"],  # To find the station again if it is renamed
+        "channel_id": voice_channel.id,
+        "start_time": time.time(),
+        "requested_by": requested_by,
+        "quality": quality,
+        "current_title": get_playlist_title(original_url)
+    }
+    radio_data = current_radios[guild_id]
+
+    async def title_changed(title: str):
+        if current_radios.get(guild_id) is not radio_data:
+            return  # Station changed in the meantime
+        radio_data["current_title"] = title
+        await upsert_player_message(client, guild_id)
+
+    # Follow in-stream title updates (ICY metadata) on a separate connection, skipping known redirects
+    health = health_status.get(original_url)
+    radio_data["icy_reader"] = IcyReader((health.resolved_url if health else None) or resolved_url, title_changed,
+                                         auth=get_stream_auth(original_url))
+    radio_data["icy_reader"].start()
+    if config["bot"]["watchdog_enabled"]:
+        radio_data["watchdog"] = StreamWatchdog(client, guild_id, source, config["bot"]["watchdog_interval"])
+        radio_data["watchdog"].start()
+    save_state()  # Persist state
+    
+    # Multi-server status (show total servers instead of specific station)
+    active_servers = len(current_radios)
+    await update_presence(client)
+    await upsert_player_message(client, guild_id)
+    
+    logger.info(f"🎵 Now playing '{station_name}' in '{guild.name}' ({active_servers} total active servers)")
+    return current_radios[guild_id]
+
+class BrowserResultSelect(ui.Select):
+    def __init__(self, results: List[radio_browser.BrowserStation]):
+        self.results = {result.stationuuid: result for result in results}
+        options = [
+            SelectOption(
+                label=result.name[:100] or "Unnamed station",
+                description=f"{result.codec or '?'} {result.bitrate or '?'}kbps • {result.country or 'Unknown'}"[:100],
+                value=result.stationuuid
+            )
+            for result in results[:25]
+        ]
+        super().__init__(placeholder="Show station details...", min_values=1, max_values=1, options=options)
+
+    async def callback(self, interaction: Interaction):
+        result = self.results[self.values[0]]
+        embed = Embed(
+            title=f"📡 {result.name}",
+            description=f"Admins can add this station with:\n`/radio browser import {result.stationuuid}`",
+            color=discord.Color.blue()
+        )
+        embed.add_field(name="Stream", value=result.url_resolved[:1024] or "Unknown", inline=False)
+        embed.add_field(name="Format", value=f"{result.codec or '?'} • {result.bitrate or '?'} kbps", inline=True)
+        embed.add_field(name="Country", value=result.country or "Unknown", inline=True)
+        if result.tags:
+            embed.add_field(name="Tags", value=result.tags[:1024], inline=False)
+        embed.set_footer(text="Alastor - The Radio Daemon • Data from radio-browser.info")
+        await safe_send_message(interaction, embed=embed, ephemeral=True)
+
+class BrowserResultView(ui.View):
+    def __init__(self, results: List[radio_browser.BrowserStation]):
+        super().__init__(timeout=120)
+        self.add_item(BrowserResultSelect(results))
+
+class RadioManager:
+    """
+    The playback state of this module behind one object. Command handlers take a manager
+    instead of reaching into current_radios, so tests can pass a fake without voice connections.
+    """
+
+    async def play_station(self, client, guild, voice_channel, station_name: str, requested_by: Optional[int] = None) -> Dict:
+        return await start_station(client, guild, voice_channel, station_name, requested_by)
+
+    async def stop_station(self, client, guild_id: int) -> Optional[str]:
+        return await stop_station(client, guild_id)
+
+    def get_current_station(self, guild_id: int) -> Optional[str]:
+        radio_data = current_radios.get(guild_id)
+        return radio_data["name"] if radio_data else None
+
+    async def check_stream_health(self, url: str, station_name: Optional[str] = None) -> tuple[bool, str]:
+        return await check_stream_health(url, station_name)
+
+    def get_config(self) -> dict:
+        return config
+
+    def get_active_streams_count(self) -> int:
+        return len(current_radios)
+
+    def set_player_message(self, guild_id: int, message_id: Optional[int]):
+        guild_settings.setdefault(guild_id, {})["player_message"] = message_id
+        save_state()
+
+    def get_player_message(self, guild_id: int) -> Optional[int]:
+        return guild_settings.get(guild_id, {}).get("player_message")
+
+radio_manager = RadioManager()
+
+async def handle_stop_command(manager: RadioManager, interaction: Interaction):
+    """/radio stop: stop the station of the interaction's guild and leave the voice channel"""
+    guild_id = interaction.guild_id
+    station_name = manager.get_current_station(guild_id)
+    if station_name is None:
+        embed = Embed(
+            title="📻 No Radio Playing",
+            description="No radio is currently playing on this server.\n\nUse `/radio play` or `/radio list` to start playing.",
+            color=discord.Color.yellow()
+        )
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await safe_send_message(interaction, embed=embed)
+        return
+
+    guild_name = interaction.guild.name if interaction.guild else "Unknown"
+    logger.info(f"📋 Stop request for '{station_name}' in '{guild_name}' by {interaction.user.display_name}")
+
+    await manager.stop_station(interaction.client, guild_id)
+
+    embed = Embed(
+        title="⏹️ Radio Stopped",
+        description=f"**{station_name}** has been stopped and I've left the voice channel.",
+        color=discord.Color.green()
+    )
+    embed.set_footer(text="Alastor - The Radio Daemon")
+    await safe_send_message(interaction, embed=embed)
+
+class RadioCog(commands.Cog):
+    def __init__(self, bot: commands.Bot):
+        self.bot = bot
+
+    async def cog_load(self):
+        interval = config["bot"]["health_check_interval"]
+        if interval:
+            self.health_check_loop.change_interval(seconds=interval)
+            self.health_check_loop.start()
+        self.station_of_the_day_loop.start()
+        self.stale_stream_loop.start()
+        self.player_update_loop.start()
+        self.schedule_loop.start()
+        if config["bot"]["status_rotation_secs"]:
+            self.status_rotation_loop.change_interval(seconds=config["bot"]["status_rotation_secs"])
+            self.status_rotation_loop.start()
+
+    async def cog_unload(self):
+        self.health_check_loop.cancel()
+        self.station_of_the_day_loop.cancel()
+        self.stale_stream_loop.cancel()
+        self.player_update_loop.cancel()
+        self.schedule_loop.cancel()
+        self.status_rotation_loop.cancel()
+
+    @tasks.loop(seconds=60)
+    async def status_rotation_loop(self):
+        """Show the next playing station in the bot's status"""
+        try:
+            await update_presence(self.bot, advance=True)
+        except Exception as e:
+            logger.error(f"❌ Status rotation failed: {e}")
+
+    @status_rotation_loop.before_loop
+    async def before_status_rotation(self):
+        await self.bot.wait_until_ready()
+
+    @tasks.loop(minutes=1)
+    async def schedule_loop(self):
+        """Follow the station schedules in guilds with auto-join"""
+        try:
+            await apply_station_schedules(self.bot)
+        except Exception as e:
+            logger.error(f"❌ Station schedule check failed: {e}")
+
+    @schedule_loop.before_loop
+    async def before_schedule(self):
+        await self.bot.wait_until_ready()
+
+    @tasks.loop(minutes=1)
+    async def player_update_loop(self):
+        """Refresh the player messages so their uptime keeps ticking"""
+        for guild_id, _ in get_all_active_streams():
+            if guild_settings.get(guild_id, {}).get("player_channel"):
+                await upsert_player_message(self.bot, guild_id)
+
+    @player_update_loop.before_loop
+    async def before_player_update(self):
+        await self.bot.wait_until_ready()
+
+    @tasks.loop(minutes=5)
+    async def stale_stream_loop(self):
+        """Clean up streams whose voice connection was closed without stop_station"""
+        try:
+            await prune_stale_streams(self.bot)
         except Exception as e:
-            logger.error(f"❌ Voice connection error: {e}")
-            embed = Embed(
-                title="❌ Connection Error",
-                description=f"Error joining voice channel: {str(e)[:200]}{'...' if len(str(e)) > 200 else ''}",
-                color=discord.Color.red()
-            )
-            embed.set_footer(text="Alastor - The Radio Daemon")
-            await safe_send_message(interaction, embed=embed, ephemeral=True)
+            logger.error(f"❌ Stale stream cleanup failed: {e}")
+
+    @stale_stream_loop.before_loop
+    async def before_stale_stream_cleanup(self):
+        await self.bot.wait_until_ready()
+
+    @tasks.loop(seconds=600)
+    async def health_check_loop(self):
+        """Periodically check all stations so failures are noticed before someone presses play"""
+        try:
+            await check_all_stations_health()
+        except Exception as e:
+            logger.error(f"❌ Background health check failed: {e}")
+
+    @health_check_loop.before_loop
+    async def before_health_check(self):
+        await self.bot.wait_until_ready()
+
+    @tasks.loop(minutes=5)
+    async def station_of_the_day_loop(self):
+        """Announce the station of the day once per day at sotd_hour_utc"""
+        now = datetime.datetime.now(datetime.timezone.utc)
+        if now.hour != config["bot"]["sotd_hour_utc"]:
             return
+        today = now.date().isoformat()
+        for guild_id, settings in list(guild_settings.items()):
+            if settings.get("sotd_channel") and settings.get("last_sotd") != today:
+                # Mark first so a failing channel isn't retried every 5 minutes
+                settings["last_sotd"] = today
+                save_state()
+                await announce_station_of_the_day(self.bot, guild_id)
+
+    @station_of_the_day_loop.before_loop
+    async def before_station_of_the_day(self):
+        await self.bot.wait_until_ready()
+
+    # Define an app_commands.Group; all methods decorated with @radio.command will be registered as /radio <subcommand>
+    radio = app_commands.Group(name="radio", description="Manage radio stations")
+
+    @staticmethod
+    @instrument("play_radio")
+    async def play_radio_static(interaction: Interaction, station_name: str, show_loading: bool = True, intro: Optional[str] = None):
+        """Enhanced radio playback with better feedback and multi-server support"""
+        # Get guild_id first
+        guild_id = interaction.guild_id
         
-        # If we reach here, we have a successful connection
-        # Check if we need to move to a different channel
-        if voice_client and voice_client.is_connected() and voice_client.channel.id != voice_channel.id:
-            logger.info(f"🔄 Moving from {voice_client.channel.name} to {voice_channel.name}")
-            try:
-                await voice_client.move_to(voice_channel)
-                logger.info(f"✅ Successfully moved to {voice_channel.name}")
-            except Exception as e:
-                logger.error(f"❌ Voice move error: {e}")
+        # Log the play request
+        guild_name = interaction.guild.name if interaction.guild else "Unknown"
+        user_name = f"{interaction.user.display_name} ({interaction.user.name})"
+        logger.info(f"🎵 Play request: '{station_name}' by {user_name} in '{guild_name}'")
+        
+        # Show loading indicator
+        if show_loading:
+            loading_embed = Embed(
+                title="🔄 Loading...",
+                description=f"Connecting to **{station_name}**...",
+                color=discord.Color.orange()
+            )
+            loading_embed.set_footer(text="Alastor - The Radio Daemon")
+            await safe_send_message(interaction, embed=loading_embed, ephemeral=False)
+        # Make sure the user gets an answer even if something unexpected fails
+        async with ResponseGuard(interaction, replace_original=show_loading) as guard:
+            available_stations = get_available_stations(guild_id)
+            station_name = resolve_station_name(guild_id, station_name) or station_name
+            if station_name not in available_stations:
+                suggestions = [name for name, _ in rank_stations(guild_id, station_name)]
+                description = f"Station **{station_name}** does not exist."
+                if suggestions:
+                    description += "\n\nDid you mean: " + ", ".join(f"**{name}**" for name in suggestions) + "?"
+                description += "\n\nUse `/radio list` to see available stations or `/station add` to add new ones."
+                embed = Embed(title="❌ Station Not Found", description=description, color=discord.Color.red())
+                embed.set_footer(text="Alastor - The Radio Daemon")
+                await guard.send(embed=embed, ephemeral=True,
+                                 view=StationSuggestionView(suggestions) if suggestions else None)
+                return
+
+            voice_channel = getattr(interaction.user.voice, "channel", None)
+            if not voice_channel:
                 embed = Embed(
-                    title="❌ Move Error",
-                    description=f"Error moving to voice channel: {e}",
+                    title="Error",
+                    description="You must be in a voice channel to play radio.",
                     color=discord.Color.red()
                 )
                 embed.set_footer(text="Alastor - The Radio Daemon")
-                await safe_send_message(interaction, embed=embed, ephemeral=True)
+                await guard.send(embed=embed, ephemeral=True)
                 return
 
-        original_url = available_stations[station_name]["url"]
-        logger.info(f"🔗 Resolving stream URL for '{station_name}': {original_url[:60]}...")
-        resolved_url = await resolve_stream_url(original_url)
-        if resolved_url is None:
-            logger.error(f"❌ Failed to resolve stream URL for '{station_name}'")
-            embed = Embed(
-                title="❌ Stream Error",
-                description=f"Failed to retrieve stream URL for **{station_name}**.\n\nThe station may be offline or the URL is invalid.",
-                color=discord.Color.red()
-            )
-            embed.set_footer(text="Alastor - The Radio Daemon")
-            if show_loading:
-                await interaction.edit_original_response(embed=embed)
-            else:
-                await safe_send_message(interaction, embed=embed, ephemeral=True)
-            return
-        
-        logger.info(f"✅ Stream URL resolved successfully for '{station_name}'")
+            try:
+                check_station_access(interaction.user, station_name, available_stations[station_name], voice_channel)
+            except AlastorError as e:
+                embed = Embed(title=e.title, description=e.description, color=discord.Color.red())
+                embed.set_footer(text="Alastor - The Radio Daemon")
+                await guard.send(embed=embed, ephemeral=True)
+                return
 
-        # Start playing the stream with improved FFmpeg options
-        logger.info(f"🎵 Starting playback of '{station_name}'")
-        logger.info(f"🔗 Final stream URL: {resolved_url}")
-        
-        # Test the URL first with a simple HTTP request
-        try:
-            async with aiohttp.ClientSession(timeout=aiohttp.ClientTimeout(total=10)) as session:
-                async with session.head(resolved_url) as response:
-                    logger.info(f"📡 Stream test response: {response.status} - {response.headers.get('content-type', 'unknown')}")
-                    if response.status >= 400:
-                        raise Exception(f"Stream returned HTTP {response.status}")
-        except Exception as e:
-            logger.error(f"❌ Stream connectivity test failed for '{station_name}': {e}")
+            # Let users know the bot is there while the stream is still connecting
+            on_joined = None
+            current_vc = interaction.guild.voice_client
+            already_joined = current_vc is not None and current_vc.is_connected() and current_vc.channel == voice_channel
+            if show_loading and config["bot"].get("join_announce", True) and not already_joined:
+                async def on_joined():
+                    joined_embed = Embed(
+                        description=f"📡 Joined {voice_channel.mention} and connecting to stream…",
+                        color=discord.Color.orange()
+                    )
+                    joined_embed.set_footer(text="Alastor - The Radio Daemon")
+                    try:
+                        await interaction.edit_original_response(embed=joined_embed)
+                    except discord.HTTPException as e:
+                        logger.debug(f"Could not show join message: {e}")
+
+            try:
+                radio_data = await start_station(interaction.client, interaction.guild, voice_channel, station_name, interaction.user.id, on_joined=on_joined)
+            except AlastorError as e:
+                embed = Embed(title=e.title, description=e.description, color=discord.Color.red())
+                embed.set_footer(text="Alastor - The Radio Daemon")
+                if show_loading:
+                    await guard.edit_original(embed=embed)
+                else:
+                    await guard.send(embed=embed, ephemeral=True)
+                return
+            radio_data["text_channel_id"] = interaction.channel_id  # Where to report later problems with the stream
+
+            # Warm the cache for the "Next" button
+            next_station = get_adjacent_station(guild_id, Direction.NEXT)
+            if next_station and next_station != station_name:
+                task = asyncio.create_task(preload_station(guild_id, next_station))
+                preload_tasks.add(task)
+                task.add_done_callback(preload_tasks.discard)
+
+            view = StationControlView(guild_id)
+            if is_compact(guild_id):
+                line = build_compact_line(guild_id)
+                if intro:
+                    line = f"{intro}\n{line}"
+                if show_loading:
+                    await guard.edit_original(content=line, embed=None, view=view)
+                else:
+                    await guard.send(content=line, view=view)
+                await send_play_notification(interaction, station_name)
+                return
+
+            quality = radio_data["quality"]
+            description = f"**{station_name}** is now playing in {voice_channel.mention}."
+            if intro:
+                description = f"{intro}\n\n{description}"
             embed = Embed(
-                title="❌ Stream Unavailable",
-                description=f"**{station_name}** is currently offline or unreachable.\n\nError: {str(e)}",
-                color=discord.Color.red()
+                title="📻 Radio Started",
+                description=description,
+                color=discord.Color.green()
             )
+            embed.add_field(name="🔊 Channel", value=voice_channel.name, inline=True)
+            embed.add_field(name="🎵 Quality", value=quality.capitalize() if quality else "Auto", inline=True)
+            homepage = available_stations[station_name].get("homepage")
+            if homepage:
+                embed.add_field(name="🌐 Website", value=f"[Visit Website]({homepage})", inline=True)
+            thumbnail = get_station_thumbnail(available_stations[station_name])
+            if thumbnail:
+                embed.set_thumbnail(url=thumbnail)
             embed.set_footer(text="Alastor - The Radio Daemon")
+
             if show_loading:
-                await interaction.edit_original_response(embed=embed)
+                await guard.edit_original(embed=embed, view=view)
             else:
-                await safe_send_message(interaction, embed=embed, ephemeral=True)
-            return
-        
-        # Try different FFmpeg configurations and sources
-        ffmpeg_path = "/opt/homebrew/bin/ffmpeg"  # Explicit path for macOS Homebrew
-        
-        # Try different approaches
-        approaches = [
-            {
-                "name": "FFmpegOpusAudio (recommended for Discord)",
-                "source_type": "opus",
-                "before_options": "-reconnect 1 -reconnect_streamed 1 -user_agent 'Mozilla/5.0'",
-                "options": "-vn"
-            },
-            {
-                "name": "FFmpegPCMAudio with explicit path",
-                "source_type": "pcm",
-                "executable": ffmpeg_path,
-                "before_options": "-reconnect 1 -user_agent 'Mozilla/5.0'", 
-                "options": "-vn -f s16le -ar 48000 -ac 2"
-            },
-            {
-                "name": "FFmpegPCMAudio basic",
-                "source_type": "pcm",
-                "options": "-vn"
-            }
-        ]
-        
-        for config in approaches:
-            try:
-                logger.info(f"🔧 Trying {config['name']} for '{station_name}'")
-                
-                # Prepare kwargs
-                kwargs = {k: v for k, v in config.items() if k not in ['name', 'source_type']}
-                
-                # Create appropriate source
-                if config['source_type'] == 'opus':
-                    source = discord.FFmpegOpusAudio(resolved_url, **kwargs)
-                else:
-                    source = discord.FFmpegPCMAudio(resolved_url, **kwargs)
-                
-                # Start playing
-                voice_client.play(source, after=lambda e: logger.error(f"❌ Player error for '{station_name}': {e}") if e else logger.info(f"⏹️ Playback ended for '{station_name}'"))
-                logger.info(f"✅ Successfully started playing '{station_name}' with {config['name']}")
-                break
-                
-            except Exception as e:
-                logger.error(f"❌ {config['name']} failed for '{station_name}': {str(e) or 'Unknown error'}")
-                
-                # If this was the last attempt, show error
-                if config == approaches[-1]:
-                    embed = Embed(
-                        title="❌ Playback Error",
-                        description=f"Could not play **{station_name}**.\n\nThis might be due to:\n• FFmpeg compatibility issues on macOS\n• Stream format not supported\n• Network connectivity problems\n\nError: {str(e)[:150]}{'...' if len(str(e)) > 150 else ''}",
-                        color=discord.Color.red()
-                    )
-                    embed.set_footer(text="Alastor - The Radio Daemon")
-                    if show_loading:
-                        await interaction.edit_original_response(embed=embed)
-                    else:
-                        await safe_send_message(interaction, embed=embed, ephemeral=True)
-                    return
-
-        # Enhanced state tracking
-        current_radios[guild_id] = {
-            "name": station_name, 
-            "voice_client": voice_client,
-            "url": resolved_url,
-            "start_time": time.time()
-        }
-        save_state()  # Persist state
-        
-        # Multi-server status (show total servers instead of specific station)
-        active_servers = len(current_radios)
-        await interaction.client.change_presence(
-            activity=discord.Game(name=f"Radio on {active_servers} server{'s' if active_servers != 1 else ''}")
-        )
-        
-        logger.info(f"🎵 Now playing '{station_name}' in '{guild_name}' ({active_servers} total active servers)")
-        
-        embed = Embed(
-            title="📻 Radio Started",
-            description=f"**{station_name}** is now playing in {voice_channel.mention}.",
-            color=discord.Color.green()
-        )
-        embed.add_field(name="🔊 Channel", value=voice_channel.name, inline=True)
-        embed.add_field(name="🎵 Quality", value="Auto", inline=True)
-        embed.set_footer(text="Alastor - The Radio Daemon")
-        
-        view = StationControlView(guild_id)
-        if show_loading:
-            await interaction.edit_original_response(embed=embed, view=view)
-        else:
-            await safe_send_message(interaction, embed=embed, view=view)
+                await guard.send(embed=embed, view=view)
+            await send_play_notification(interaction, station_name)
 
     @radio.command(name="play", description="Play a radio station by name.")
     @app_commands.describe(name="Station name")
@@ -641,91 +2623,97 @@ class RadioCog(commands.Cog):
     async def play(self, interaction: Interaction, name: str):
         await self.play_radio_static(interaction, name)
 
-    @radio.command(name="stop", description="Stop the currently playing radio and leave the voice channel.")
-    async def stop(self, interaction: Interaction):
-        await self.stop_radio_static(interaction)
-        
-    @staticmethod
-    async def stop_radio_static(interaction: Interaction):
-        guild_id = interaction.guild_id
-        if guild_id not in current_radios:
+    @radio.command(name="random", description="Play a random station.")
+    @app_commands.describe(genre="Only pick stations with this tag (e.g. jazz)")
+    async def random_station(self, interaction: Interaction, genre: Optional[str] = None):
+        station_name = pick_random_station(interaction.guild_id, genre)
+        if station_name is None:
             embed = Embed(
-                title="📻 No Radio Playing",
-                description="No radio is currently playing on this server.\n\nUse `/radio play` or `/radio list` to start playing.",
-                color=discord.Color.yellow()
+                title="❌ No Stations Found",
+                description=f"No stations match the genre **{genre}**." if genre else "No radio stations configured yet.",
+                color=discord.Color.red()
             )
             embed.set_footer(text="Alastor - The Radio Daemon")
-            await safe_send_message(interaction, embed=embed)
+            await safe_send_message(interaction, embed=embed, ephemeral=True)
             return
 
-        station_name = current_radios[guild_id]["name"]
-        voice_client = current_radios[guild_id]["voice_client"]
-        guild_name = interaction.guild.name if interaction.guild else "Unknown"
-        
-        logger.info(f"📋 Stop request for '{station_name}' in '{guild_name}' by {interaction.user.display_name}")
-        
-        # Graceful shutdown
-        try:
-            voice_client.stop()
-            await asyncio.wait_for(voice_client.disconnect(force=True), timeout=5.0)
-            logger.info(f"✅ Successfully stopped and disconnected from '{guild_name}'")
-        except asyncio.TimeoutError:
-            logger.warning(f"⏰ Voice client disconnect timed out for '{guild_name}'")
-        except Exception as e:
-            logger.error(f"❌ Error disconnecting from '{guild_name}': {e}")
+        logger.info(f"🎲 Random pick for {interaction.user.display_name}: '{station_name}' (genre: {genre or 'any'})")
+        await self.play_radio_static(interaction, station_name, intro=f"🎲 Picked: **{station_name}**")
 
-        del current_radios[guild_id]
+    @radio.command(name="loop", description="Restart the station automatically when its stream ends.")
+    @app_commands.describe(mode="On to keep the station playing, off to stop when the stream ends")
+    @app_commands.choices(mode=[app_commands.Choice(name="On", value="on"), app_commands.Choice(name="Off", value="off")])
+    async def loop(self, interaction: Interaction, mode: app_commands.Choice[str]):
+        guild_id = interaction.guild_id
+        guild_settings.setdefault(guild_id, {})["loop"] = mode.value == "on"
         save_state()
-        
-        # Update presence
-        active_servers = len(current_radios)
-        if active_servers > 0:
-            await interaction.client.change_presence(
-                activity=discord.Game(name=f"Radio on {active_servers} server{'s' if active_servers != 1 else ''}")
-            )
-        else:
-            await interaction.client.change_presence(activity=discord.Game(name="Radio"))
-            
+
+        logger.info(f"🔁 {interaction.user.display_name} turned loop mode {mode.value} in {interaction.guild.name}")
+
         embed = Embed(
-            title="⏹️ Radio Stopped",
-            description=f"**{station_name}** has been stopped and I've left the voice channel.",
+            title="🔁 Loop Enabled" if mode.value == "on" else "➡️ Loop Disabled",
+            description="The station is restarted automatically when its stream ends." if mode.value == "on" else "Playback stops when the stream ends.",
             color=discord.Color.green()
         )
         embed.set_footer(text="Alastor - The Radio Daemon")
         await safe_send_message(interaction, embed=embed)
+        await upsert_player_message(interaction.client, guild_id)
+
+    @radio.command(name="stop", description="Stop the currently playing radio and leave the voice channel.")
+    async def stop(self, interaction: Interaction):
+        await self.stop_radio_static(interaction)
+        
+    @staticmethod
+    @instrument("stop_radio")
+    async def stop_radio_static(interaction: Interaction):
+        await handle_stop_command(radio_manager, interaction)
 
     @radio.command(name="info", description="Show detailed information about the currently playing radio station.")
     async def info(self, interaction: Interaction):
         await self.show_info_static(interaction)
         
     @staticmethod
+    @instrument("show_info")
     async def show_info_static(interaction: Interaction):
         guild_id = interaction.guild_id
+        if guild_id in current_radios and is_compact(guild_id):
+            await safe_send_message(interaction, content=build_compact_line(guild_id), view=StationControlView(guild_id))
+            return
         if guild_id in current_radios:
             radio_data = current_radios[guild_id]
             station_name = radio_data["name"]
-            start_time = radio_data.get("start_time", time.time())
-            uptime_seconds = int(time.time() - start_time)
-            
-            # Format uptime
-            hours, remainder = divmod(uptime_seconds, 3600)
-            minutes, seconds = divmod(remainder, 60)
-            uptime_str = f"{hours:02d}:{minutes:02d}:{seconds:02d}"
             
             embed = Embed(
                 title="📻 Current Radio Station",
                 description=f"**{station_name}** is currently playing.",
                 color=discord.Color.blue()
             )
-            embed.add_field(name="🕰️ Uptime", value=uptime_str, inline=True)
             embed.add_field(name="🏛️ Server", value=interaction.guild.name, inline=True)
             embed.add_field(name="🔗 Status", value="✅ Connected", inline=True)
+            if radio_data.get("current_title"):
+                embed.add_field(name="🎶 Now Playing", value=radio_data["current_title"][:1024], inline=False)
+            embed.add_field(name="🕰️ Uptime", value=format_uptime_field(get_station_uptime(guild_id)), inline=False)
+            quality = radio_data.get("quality")
+            embed.add_field(name="🎵 Quality", value=quality.capitalize() if quality else "Auto", inline=True)
             
-            voice_client = radio_data["voice_client"]
-            if voice_client and voice_client.channel:
-                embed.add_field(name="🔊 Channel", value=voice_client.channel.name, inline=True)
-                embed.add_field(name="👥 Listeners", value=str(len(voice_client.channel.members) - 1), inline=True)
-                
+            # Left out if the guild is unavailable and its channels aren't cached
+            listeners = count_listeners(radio_data)
+            if listeners is not None:
+                embed.add_field(name="🔊 Voice Channel", value=f"<#{radio_data['channel_id']}>", inline=True)
+                embed.add_field(name="🎧 Listeners", value=str(listeners), inline=True)
+
+            station = get_available_stations(guild_id).get(station_name)
+            details = get_stream_details(station) if station else ""
+            if details:
+                embed.add_field(name="🎚️ Format", value=details, inline=True)
+            if station and station.get("language"):
+                embed.add_field(name="🗣️ Language", value=station["language"], inline=True)
+            if station and station.get("homepage"):
+                embed.add_field(name="🌐 Website", value=f"[Visit Website]({station['homepage']})", inline=True)
+            thumbnail = get_station_thumbnail(station)
+            if thumbnail:
+                embed.set_thumbnail(url=thumbnail)
+
             view = StationControlView(guild_id)
         else:
             embed = Embed(
@@ -738,11 +2726,73 @@ class RadioCog(commands.Cog):
         embed.set_footer(text="Alastor - The Radio Daemon")
         await safe_send_message(interaction, embed=embed, view=view)
 
+    @radio.command(name="stats", description="Show playback statistics for this server.")
+    async def stats(self, interaction: Interaction):
+        guild_id = interaction.guild_id
+        guild_stats = stats_tracker.get(guild_id)
+        if not guild_stats or not guild_stats["total_plays"]:
+            embed = Embed(
+                title="📊 Radio Statistics",
+                description="No playback statistics recorded on this server yet.\n\nUse `/radio play` to start listening!",
+                color=discord.Color.yellow()
+            )
+            embed.set_footer(text="Alastor - The Radio Daemon")
+            await safe_send_message(interaction, embed=embed)
+            return
+
+        embed = Embed(
+            title="📊 Radio Statistics",
+            description=f"Playback statistics for **{interaction.guild.name}**",
+            color=discord.Color.blue()
+        )
+        top_stations = stats_tracker.top_stations(guild_id)
+        embed.add_field(
+            name="🏆 Top 3 stations",
+            value="\n".join(f"{i}. **{name}** — {count} play{'s' if count != 1 else ''}" for i, (name, count) in enumerate(top_stations, 1)),
+            inline=False
+        )
+        top_user = stats_tracker.top_user(guild_id)
+        if top_user:
+            user_id, count = top_user
+            embed.add_field(name="🎧 Most active DJ", value=f"<@{user_id}> ({count} play{'s' if count != 1 else ''})", inline=True)
+        embed.add_field(name="🕰️ Total listening time", value=format_duration(guild_stats["total_seconds"]), inline=True)
+        embed.add_field(name="▶️ Total plays", value=str(guild_stats["total_plays"]), inline=True)
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await safe_send_message(interaction, embed=embed)
+
     @radio.command(name="list", description="Browse all available radio stations with pagination.")
-    async def list(self, interaction: Interaction):
+    @app_commands.describe(
+        country="Only show stations from this country (two-letter code, e.g. US)",
+        language="Only show stations in this language (e.g. en; fr also finds fr-CA)"
+    )
+    @app_commands.autocomplete(country=get_country_autocomplete, language=get_language_autocomplete)
+    async def list(self, interaction: Interaction, country: Optional[str] = None, language: Optional[str] = None):
         guild_id = interaction.guild_id
         available_stations = get_available_stations(guild_id)
         
+        if (country or language) and available_stations:
+            matches = [name for name, station in available_stations.items()
+                       if (not country or (station.get("country") or "").strip().lower() == country.strip().lower())
+                       and (not language or station_matches_language(station, language))]
+            filters = " ".join(part for part in (
+                " ".join(part for part in ("from", country_flag(country), country.upper()) if part) if country else None,
+                f"in {language}" if language else None
+            ) if part)
+            if not matches:
+                await safe_send_message(interaction, content=f"No stations {filters}.", ephemeral=True)
+                return
+            view = ui.View(timeout=120)
+            view.add_item(RadioSelectMenu(guild_id, stations=matches[:25]))
+            embed = Embed(
+                title=f"📻 Stations {filters}",
+                description=f"**{len(matches)}** station{'s' if len(matches) != 1 else ''}"
+                            + (" (showing the first 25)" if len(matches) > 25 else "") + ":",
+                color=discord.Color.blue()
+            )
+            embed.set_footer(text="Alastor - The Radio Daemon")
+            await safe_send_message(interaction, embed=embed, view=view)
+            return
+
         if not available_stations:
             embed = Embed(
                 title="📻 No Stations Available",
@@ -758,15 +2808,224 @@ class RadioCog(commands.Cog):
         total_stations = len(available_stations)
         
         view = RadioListView(guild_id, page=0)
+        description = f"Choose from **{total_stations}** stations ({global_count} global, {server_count} server):"
+        genre_lines = build_genre_lines(available_stations)
+        if genre_lines:
+            description += "\n\n" + "\n".join(genre_lines)
+        schedule_lines = [f"🕒 **{name}**: " + "; ".join(str(window) for window in parse_schedule(station))
+                          for name, station in available_stations.items() if parse_schedule(station)]
+        if schedule_lines:
+            description += "\n\n**Schedule** (with `/setup auto-join`):\n" + "\n".join(schedule_lines[:10])
+        description += "\n\nSelect a station from the dropdown menu below:\n🟢 Online • 🔴 Offline • 🟡 Not checked yet • ⛔ Disabled • 🌐 Has a website"
         embed = Embed(
             title="📻 Available Radio Stations",
-            description=f"Choose from **{total_stations}** stations ({global_count} global, {server_count} server):\n\nSelect a station from the dropdown menu below:",
+            description=description,
             color=discord.Color.blue()
         )
         embed.set_footer(text=f"Alastor - The Radio Daemon • Page 1 of {(total_stations + 24) // 25}")
         await safe_send_message(interaction, embed=embed, view=view)
         
 
+    @radio.command(name="leaderboard", description="Show the most played stations on this server and everywhere.")
+    async def leaderboard(self, interaction: Interaction):
+        embed = build_leaderboard_embed(interaction.guild_id)
+        view = LeaderboardView(interaction.guild_id) if interaction.guild_id else None
+        await safe_send_message(interaction, embed=embed, view=view)
+
+    @radio.command(name="health", description="Show the health of all stations (Manage Server or DJ role).")
+    async def health(self, interaction: Interaction):
+        check_dj_permission(interaction, "view the station health report")
+        guild_id = interaction.guild_id
+
+        if not health_status:
+            # The background checker hasn't run yet; checking all stations may take a few seconds
+            await interaction.response.defer(thinking=True)
+            await check_all_stations_health()
+
+        await safe_send_message(interaction, embed=build_health_embed(guild_id, 0), view=HealthReportView(guild_id))
+
+    # Radio Browser station discovery (https://www.radio-browser.info)
+    browser = app_commands.Group(name="browser", description="Discover stations from the Radio Browser directory", parent=radio)
+
+    @browser.command(name="search", description="Search the public Radio Browser directory.")
+    @app_commands.describe(query="Station name to search for")
+    async def browser_search(self, interaction: Interaction, query: str):
+        await interaction.response.defer(thinking=True)
+        try:
+            results = await radio_browser.search_stations(query)
+        except Exception as e:
+            logger.error(f"❌ Radio Browser search failed: {e}")
+            embed = Embed(
+                title="❌ Search Failed",
+                description="The Radio Browser directory could not be reached. Please try again later.",
+                color=discord.Color.red()
+            )
+            embed.set_footer(text="Alastor - The Radio Daemon")
+            await safe_send_message(interaction, embed=embed)
+            return
+
+        if not results:
+            embed = Embed(
+                title="🔎 No Stations Found",
+                description=f"No stations match **{query}**.",
+                color=discord.Color.yellow()
+            )
+            embed.set_footer(text="Alastor - The Radio Daemon")
+            await safe_send_message(interaction, embed=embed)
+            return
+
+        embed = Embed(
+            title=f"🔎 Radio Browser: {query}",
+            description="\n".join(
+                f"**{result.name}** — {result.codec or '?'} {result.bitrate or '?'}kbps • {result.country or 'Unknown'}"
+                for result in results
+            )[:4096],
+            color=discord.Color.blue()
+        )
+        embed.set_footer(text="Alastor - The Radio Daemon • Data from radio-browser.info")
+        await safe_send_message(interaction, embed=embed, view=BrowserResultView(results))
+
+    @browser.command(name="import", description="Add a Radio Browser station as a global station (Admin only).")
+    @app_commands.describe(stationuuid="Station UUID from /radio browser search")
+    async def browser_import(self, interaction: Interaction, stationuuid: str):
+        if not is_admin(interaction.user):
+            embed = Embed(
+                title="❌ Permission Denied",
+                description="Only server administrators can import stations.",
+                color=discord.Color.red()
+            )
+            await safe_send_message(interaction, embed=embed, ephemeral=True)
+            return
+
+        await interaction.response.defer(ephemeral=True, thinking=True)
+        try:
+            result = await radio_browser.get_station(stationuuid.strip())
+        except Exception as e:
+            logger.error(f"❌ Radio Browser lookup failed: {e}")
+            result = None
+
+        error = None
+        if result is None or not result.url_resolved:
+            error = "Station could not be found in the Radio Browser directory."
+        elif any(existing.lower() == result.name.lower() for existing in RADIOS):
+            error = f"A global station named **{result.name}** already exists."
+        else:
+            is_safe, safety_message = is_safe_url(result.url_resolved)
+            if not is_safe:
+                error = safety_message
+            else:
+                is_healthy, health_message = await check_stream_health(result.url_resolved, result.name)
+                if not is_healthy:
+                    error = f"Stream health check failed: {health_message}"
+
+        if error:
+            embed = Embed(title="❌ Import Failed", description=error, color=discord.Color.red())
+            embed.set_footer(text="Alastor - The Radio Daemon")
+            await safe_send_message(interaction, embed=embed, ephemeral=True)
+            return
+
+        add_global_station(
+            result.name,
+            result.url_resolved,
+            interaction.user.id,
+            description=result.tags,
+            bitrate=result.bitrate,
+            format=result.codec,
+            language=result.language if is_valid_language(result.language) else None
+        )
+        logger.info(f"📥 Admin {interaction.user.display_name} imported '{result.name}' from Radio Browser")
+
+        embed = Embed(
+            title="✅ Station Imported",
+            description=f"**{result.name}** is now available on all servers.",
+            color=discord.Color.green()
+        )
+        embed.add_field(name="URL", value=result.url_resolved[:100] + ("..." if len(result.url_resolved) > 100 else ""), inline=False)
+        embed.add_field(name="Format", value=f"{result.codec or '?'} • {result.bitrate or '?'} kbps", inline=True)
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await safe_send_message(interaction, embed=embed, ephemeral=True)
+
+    # Alternative names for stations, used by /radio play and search
+    alias = app_commands.Group(name="alias", description="Manage alternative station names", parent=radio)
+
+    @alias.command(name="add", description="Add an alternative name for a station (DJ only).")
+    @app_commands.describe(station="Station to add the alias to", alias="Alternative name, e.g. an abbreviation")
+    @app_commands.autocomplete(station=get_station_autocomplete)
+    async def alias_add(self, interaction: Interaction, station: str, alias: str):
+        check_alias_permission(interaction, station)
+        guild_id = interaction.guild_id
+        alias = alias.strip()
+        available_stations = get_available_stations(guild_id)
+
+        error = None
+        if station not in available_stations:
+            error = f"Station **{station}** does not exist."
+        elif not alias:
+            error = "The alias can't be empty."
+        elif any(name.lower() == alias.lower() for name in available_stations):
+            error = f"**{alias}** is already the name of a station."
+        elif find_station_by_alias(guild_id, alias):
+            error = f"**{alias}** is already an alias of **{find_station_by_alias(guild_id, alias)}**."
+        if error:
+            embed = Embed(title="❌ Alias Not Added", description=error, color=discord.Color.red())
+            embed.set_footer(text="Alastor - The Radio Daemon")
+            await safe_send_message(interaction, embed=embed, ephemeral=True)
+            return
+
+        available_stations[station].setdefault("aliases", []).append(alias)
+        save_state()
+        if is_global_station(interaction.guild_id, station):
+            save_config_stations({station: {"aliases": available_stations[station]["aliases"]}})
+        logger.info(f"🏷️ {interaction.user.display_name} added alias '{alias}' to '{station}' in {interaction.guild.name}")
+
+        embed = Embed(
+            title="✅ Alias Added",
+            description=f"**{station}** can now also be played as **{alias}**.",
+            color=discord.Color.green()
+        )
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await safe_send_message(interaction, embed=embed, ephemeral=True)
+
+    @alias.command(name="remove", description="Remove an alternative name from a station (DJ only).")
+    @app_commands.describe(station="Station to remove the alias from", alias="Alias to remove")
+    @app_commands.autocomplete(station=get_station_autocomplete)
+    async def alias_remove(self, interaction: Interaction, station: str, alias: str):
+        check_alias_permission(interaction, station)
+        station_data = get_available_stations(interaction.guild_id).get(station)
+        aliases = (station_data or {}).get("aliases") or []
+        existing = next((name for name in aliases if name.lower() == alias.strip().lower()), None)
+
+        error = None
+        if station_data is None:
+            error = f"Station **{station}** does not exist."
+        elif existing is None:
+            error = f"**{alias}** is not an alias of **{station}**."
+        elif len(aliases) == 1 and not station_data.get("description"):
+            # Without a description the alias may be the only way to find the station by another name
+            error = (f"**{existing}** is the last alias of **{station}**, which has no description, "
+                     "so this will make the station harder to find.\n\nAdd a description first.")
+        if error:
+            embed = Embed(title="❌ Alias Not Removed", description=error, color=discord.Color.red())
+            embed.set_footer(text="Alastor - The Radio Daemon")
+            await safe_send_message(interaction, embed=embed, ephemeral=True)
+            return
+
+        aliases.remove(existing)
+        if not aliases:
+            del station_data["aliases"]
+        save_state()
+        if is_global_station(interaction.guild_id, station):
+            save_config_stations({station: {"aliases": station_data.get("aliases")}})
+        logger.info(f"🏷️ {interaction.user.display_name} removed alias '{existing}' from '{station}' in {interaction.guild.name}")
+
+        embed = Embed(
+            title="🗑️ Alias Removed",
+            description=f"**{existing}** no longer plays **{station}**.",
+            color=discord.Color.green()
+        )
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await safe_send_message(interaction, embed=embed, ephemeral=True)
+
     # Station management commands (public but secure)
     station = app_commands.Group(name="station", description="Add and manage radio stations")
     
@@ -778,7 +3037,7 @@ class RadioCog(commands.Cog):
     )
     async def station_add(self, interaction: Interaction, name: str, url: str, description: str = ""):
         # Check if user has admin permissions
-        if not interaction.user.guild_permissions.administrator:
+        if not is_admin(interaction.user):
             embed = Embed(
                 title="❌ Permission Denied",
                 description="Only server administrators can add stations.\n\nAsk a server admin to add stations for you.",
@@ -818,7 +3077,12 @@ class RadioCog(commands.Cog):
         )
         await safe_send_message(interaction, embed=loading_embed)
         
-        test_url = await resolve_stream_url(url)
+        try:
+            test_url = await resolve_stream_url(url)
+        except PlaylistParseError as e:
+            embed = Embed(title=e.title, description=e.description, color=discord.Color.red())
+            await interaction.edit_original_response(embed=embed)
+            return
         if test_url is None:
             embed = Embed(
                 title="❌ Invalid Stream",
@@ -860,7 +3124,7 @@ class RadioCog(commands.Cog):
     @app_commands.autocomplete(name=get_station_autocomplete)
     async def station_remove(self, interaction: Interaction, name: str):
         # Check if user has admin permissions
-        if not interaction.user.guild_permissions.administrator:
+        if not is_admin(interaction.user):
             embed = Embed(
                 title="❌ Permission Denied",
                 description="Only server administrators can remove stations.",
@@ -946,8 +3210,8 @@ class ThankYouView(ui.View):
         self.add_item(github_button)
 
 async def check_voice_channel_empty(bot, guild_id: int):
-    """Check if voice channel is empty and leave after 30 seconds"""
-    await asyncio.sleep(30)  # Wait 30 seconds
+    """Check if voice channel is empty and leave after the configured delay"""
+    await asyncio.sleep(config["bot"]["auto_leave_delay"])  # Give users a moment to rejoin
     
     # Check if still playing and channel is empty
     if guild_id not in current_radios:
@@ -965,19 +3229,7 @@ async def check_voice_channel_empty(bot, guild_id: int):
         channel = voice_client.channel
         
         # Stop and disconnect
-        voice_client.stop()
-        await voice_client.disconnect()
-        del current_radios[guild_id]
-        save_state()
-        
-        # Update presence
-        active_servers = len(current_radios)
-        if active_servers > 0:
-            await bot.change_presence(
-                activity=discord.Game(name=f"Radio on {active_servers} server{'s' if active_servers != 1 else ''}")
-            )
-        else:
-            await bot.change_presence(activity=discord.Game(name="Radio"))
+        await stop_station(bot, guild_id)
         
         # Send thank you message to a text channel
         guild = bot.get_guild(guild_id)
@@ -1016,10 +3268,20 @@ async def check_voice_channel_empty(bot, guild_id: int):
 
 # Add voice state monitoring to RadioCog
 class RadioCogEnhanced(RadioCog):
+    @commands.Cog.listener()
+    async def on_raw_reaction_add(self, payload: discord.RawReactionActionEvent):
+        """Player controls via reactions (raw event, since the player message may not be cached)"""
+        if payload.guild_id is None:
+            return
+        try:
+            await handle_player_reaction(self.bot, payload)
+        except Exception as e:
+            logger.error(f"❌ Handling player reaction failed: {e}")
+
     @commands.Cog.listener()
     async def on_voice_state_update(self, member, before, after):
         """Monitor voice channel activity for auto-leave"""
-        if member.bot:  # Ignore bot activities
+        if member.bot or not config["bot"]["auto_leave_empty"]:  # Ignore bot activities
             return
         
         # Check if someone left a channel where the bot is playing
@@ -1034,7 +3296,7 @@ class RadioCogEnhanced(RadioCog):
                     if not members_in_channel and guild_id not in auto_leave_tasks:
                         # Start auto-leave timer
                         auto_leave_tasks[guild_id] = asyncio.create_task(check_voice_channel_empty(self.bot, guild_id))
-                        logger.info(f"⏰ Started 30s auto-leave timer for {before.channel.guild.name}")
+                        logger.info(f"⏰ Started {config['bot']['auto_leave_delay']}s auto-leave timer for {before.channel.guild.name}")
                     elif members_in_channel and guild_id in auto_leave_tasks:
                         # Cancel auto-leave timer if someone joined back
                         auto_leave_tasks[guild_id].cancel()
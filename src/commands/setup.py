@@ -0,0 +1,209 @@
+import logging
+import discord
+from discord.ext import commands
+from discord import app_commands, Interaction, Embed
+import sys
+from pathlib import Path
+
+# Add the parent directory to sys.path to allow imports
+sys.path.insert(0, str(Path(__file__).parent.parent.parent))
+
+from src.config import QUALITY_TIERS
+from src.command_permissions import apply_dj_permissions
+from src.commands.radio import (
+    config,
+    guild_settings,
+    save_state,
+    safe_send_message,
+    upsert_player_message,
+    get_station_autocomplete,
+    resolve_station_name,
+)
+
+logger = logging.getLogger(__name__)
+
+def get_settings(guild_id: int) -> dict:
+    """Get (and create if missing) the settings of a guild"""
+    return guild_settings.setdefault(guild_id, {})
+
+class SetupCog(commands.Cog):
+    def __init__(self, bot: commands.Bot):
+        self.bot = bot
+
+    # Per-server configuration; hidden from members without Manage Server permission
+    guild_setup = app_commands.Group(
+        name="setup",
+        description="Configure the bot for this server",
+        default_permissions=discord.Permissions(manage_guild=True),
+        guild_only=True
+    )
+
+    @guild_setup.command(name="quality", description="Set the preferred stream quality for this server.")
+    @app_commands.describe(quality="Preferred quality tier (used when a station offers it)")
+    @app_commands.choices(quality=[app_commands.Choice(name=tier.capitalize(), value=tier) for tier in QUALITY_TIERS])
+    async def quality(self, interaction: Interaction, quality: app_commands.Choice[str]):
+        get_settings(interaction.guild_id)["quality"] = quality.value
+        save_state()
+
+        logger.info(f"⚙️ {interaction.user.display_name} set stream quality to '{quality.value}' in {interaction.guild.name}")
+
+        embed = Embed(
+            title="✅ Quality Updated",
+            description=f"Stations will play in **{quality.name}** quality where available.\n\nThe change applies the next time a station is started.",
+            color=discord.Color.green()
+        )
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await safe_send_message(interaction, embed=embed, ephemeral=True)
+
+    @guild_setup.command(name="dj-role", description="Set the role that may use DJ commands (leave empty to clear).")
+    @app_commands.describe(role="Members with this role count as DJs")
+    async def dj_role(self, interaction: Interaction, role: discord.Role = None):
+        settings = get_settings(interaction.guild_id)
+        if role:
+            settings["dj_role"] = role.id
+        else:
+            settings.pop("dj_role", None)
+        save_state()
+
+        logger.info(f"⚙️ {interaction.user.display_name} set DJ role to {role.name if role else 'none'} in {interaction.guild.name}")
+
+        embed = Embed(
+            title="✅ DJ Role Updated",
+            description=f"Members with {role.mention} can now use DJ commands." if role else "DJ role cleared. Only members with Manage Server can use DJ commands.",
+            color=discord.Color.green()
+        )
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await safe_send_message(interaction, embed=embed, ephemeral=True)
+        # After answering, since the Discord API call may take longer than an interaction may wait
+        await apply_dj_permissions(self.bot, interaction.guild_id, role.id if role else None)
+
+    @guild_setup.command(name="player-channel", description="Keep a pinned now-playing message in a channel (leave empty to disable).")
+    @app_commands.describe(channel="Text channel for the player message, e.g. #now-playing")
+    async def player_channel(self, interaction: Interaction, channel: discord.TextChannel = None):
+        settings = get_settings(interaction.guild_id)
+        settings.pop("player_message", None)  # Start with a fresh message in the new channel
+        if channel:
+            settings["player_channel"] = channel.id
+        else:
+            settings.pop("player_channel", None)
+        save_state()
+
+        logger.info(f"⚙️ {interaction.user.display_name} set player channel to {channel.name if channel else 'none'} in {interaction.guild.name}")
+
+        embed = Embed(
+            title="✅ Player Channel Updated",
+            description=f"The player message now lives in {channel.mention}." if channel else "Player channel disabled.",
+            color=discord.Color.green()
+        )
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await safe_send_message(interaction, embed=embed, ephemeral=True)
+
+        if channel:
+            await upsert_player_message(interaction.client, interaction.guild_id)
+
+    @guild_setup.command(name="sotd-channel", description="Post a random station of the day in a channel (leave empty to disable).")
+    @app_commands.describe(channel="Text channel for the daily announcement")
+    async def sotd_channel(self, interaction: Interaction, channel: discord.TextChannel = None):
+        settings = get_settings(interaction.guild_id)
+        if channel:
+            settings["sotd_channel"] = channel.id
+        else:
+            settings.pop("sotd_channel", None)
+        save_state()
+
+        logger.info(f"⚙️ {interaction.user.display_name} set station of the day channel to {channel.name if channel else 'none'} in {interaction.guild.name}")
+
+        embed = Embed(
+            title="✅ Station of the Day Updated",
+            description=f"A random station will be announced in {channel.mention} every day at {config['bot']['sotd_hour_utc']:02d}:00 UTC." if channel else "Station of the day disabled.",
+            color=discord.Color.green()
+        )
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await safe_send_message(interaction, embed=embed, ephemeral=True)
+
+    @guild_setup.command(name="compact", description="Show now-playing info as a single line instead of an embed.")
+    @app_commands.describe(mode="On for single-line messages, off for full embeds")
+    @app_commands.choices(mode=[app_commands.Choice(name="On", value="on"), app_commands.Choice(name="Off", value="off")])
+    async def compact(self, interaction: Interaction, mode: app_commands.Choice[str]):
+        settings = get_settings(interaction.guild_id)
+        settings["compact"] = mode.value == "on"
+        save_state()
+
+        logger.info(f"⚙️ {interaction.user.display_name} turned compact mode {mode.value} in {interaction.guild.name}")
+
+        embed = Embed(
+            title="✅ Compact Mode Updated",
+            description="Now-playing info is shown as a single line." if settings["compact"] else "Now-playing info is shown as a full embed.",
+            color=discord.Color.green()
+        )
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await safe_send_message(interaction, embed=embed, ephemeral=True)
+        await upsert_player_message(interaction.client, interaction.guild_id)
+
+    @guild_setup.command(name="reactions", description="Control the player message with reactions instead of commands.")
+    @app_commands.describe(mode="On to add ⏮ 🔇 ⏭ 🔁 reactions to the player message, off to remove them")
+    @app_commands.choices(mode=[app_commands.Choice(name="On", value="on"), app_commands.Choice(name="Off", value="off")])
+    async def reactions(self, interaction: Interaction, mode: app_commands.Choice[str]):
+        settings = get_settings(interaction.guild_id)
+        settings["use_reactions"] = mode.value == "on"
+        save_state()
+
+        logger.info(f"⚙️ {interaction.user.display_name} turned player reactions {mode.value} in {interaction.guild.name}")
+
+        if not settings.get("player_channel"):
+            description = "Saved. Set a player channel with `/setup player-channel` to use it."
+        elif settings["use_reactions"]:
+            description = "Listeners can react with ⏮ previous, 🔇 stop, ⏭ next and 🔁 loop on the player message."
+        else:
+            description = "The player message no longer has control reactions."
+        embed = Embed(title="✅ Player Reactions Updated", description=description, color=discord.Color.green())
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await safe_send_message(interaction, embed=embed, ephemeral=True)
+        await upsert_player_message(interaction.client, interaction.guild_id)
+
+    @guild_setup.command(name="auto-join", description="Join a voice channel and play a station whenever the bot starts.")
+    @app_commands.describe(
+        mode="On to join on startup, off to stay idle",
+        channel="Voice channel to join (keeps the previous one if empty)",
+        station="Station to play (keeps the previous one if empty)"
+    )
+    @app_commands.choices(mode=[app_commands.Choice(name="On", value="on"), app_commands.Choice(name="Off", value="off")])
+    @app_commands.autocomplete(station=get_station_autocomplete)
+    async def auto_join(self, interaction: Interaction, mode: app_commands.Choice[str],
+                        channel: discord.VoiceChannel = None, station: str = None):
+        settings = get_settings(interaction.guild_id)
+        if station:
+            station_name = resolve_station_name(interaction.guild_id, station)
+            if station_name is None:
+                embed = Embed(title="❌ Station Not Found", description=f"Station **{station}** does not exist.", color=discord.Color.red())
+                embed.set_footer(text="Alastor - The Radio Daemon")
+                await safe_send_message(interaction, embed=embed, ephemeral=True)
+                return
+            settings["default_station"] = station_name
+        if channel:
+            settings["default_voice_channel"] = channel.id
+        if mode.value == "on" and not (settings.get("default_station") and settings.get("default_voice_channel")):
+            embed = Embed(
+                title="❌ Missing Channel or Station",
+                description="Pick a voice `channel` and a `station` to turn auto-join on.",
+                color=discord.Color.red()
+            )
+            embed.set_footer(text="Alastor - The Radio Daemon")
+            await safe_send_message(interaction, embed=embed, ephemeral=True)
+            return
+        settings["auto_join"] = mode.value == "on"
+        save_state()
+
+        logger.info(f"⚙️ {interaction.user.display_name} turned auto-join {mode.value} in {interaction.guild.name}")
+
+        if settings["auto_join"]:
+            description = (f"On startup I'll join <#{settings['default_voice_channel']}> "
+                           f"and play **{settings['default_station']}**.")
+        else:
+            description = "I'll stay idle on startup."
+        embed = Embed(title="✅ Auto-Join Updated", description=description, color=discord.Color.green())
+        embed.set_footer(text="Alastor - The Radio Daemon")
+        await safe_send_message(interaction, embed=embed, ephemeral=True)
+
+async def setup(bot: commands.Bot):
+    await bot.add_cog(SetupCog(bot))
@@ -1,15 +1,530 @@
 import os
+import re
+import time
+import datetime
+import logging
 import yaml
+from dataclasses import dataclass, field
+from typing import Callable, Dict, Iterable, List, Optional, Set, Tuple
+from urllib.parse import urlparse
+import sys
+from pathlib import Path
 
-def load_config(path: str):
+# Add the parent directory to sys.path to allow imports
+sys.path.insert(0, str(Path(__file__).parent.parent))
+
+from src.errors import ConfigError
+
+logger = logging.getLogger(__name__)
+
+QUALITY_TIERS = ("low", "medium", "high")
+# Primary subtags of common BCP 47 language tags; regional variants like "fr-CA" are accepted too
+LANGUAGE_TAGS = {
+    "ar", "bg", "bn", "ca", "cs", "da", "de", "el", "en", "es", "et", "fa", "fi", "fr", "ga", "he",
+    "hi", "hr", "hu", "id", "is", "it", "ja", "ko", "lt", "lv", "ms", "mt", "nl", "no", "nb", "nn",
+    "pl", "pt", "ro", "ru", "sk", "sl", "sr", "sv", "sw", "th", "tl", "tr", "uk", "ur", "vi", "zh"
+}
+
+def is_valid_language(tag) -> bool:
+    """Check a BCP 47 tag like "en" or "fr-CA" against LANGUAGE_TAGS"""
+    if not isinstance(tag, str):
+        return False
+    primary, _, region = tag.lower().partition("-")
+    return primary in LANGUAGE_TAGS and (not region or (region.isalnum() and 2 <= len(region) <= 8))
+
+def is_valid_url(url) -> bool:
+    """Check that a value is a parseable http(s) URL"""
+    if not isinstance(url, str):
+        return False
+    parsed = urlparse(url)
+    return parsed.scheme in ("http", "https") and bool(parsed.netloc)
+
+CUSTOM_EMOJI_PATTERN = re.compile(r"^<a?:\w{2,32}:\d{15,21}>$")
+
+WEEKDAYS = ("mon", "tue", "wed", "thu", "fri", "sat", "sun")
+
+@dataclass
+class StationSchedule:
+    """
+    A weekly time window (UTC) in which guilds with auto-join switch to a station.
+    end_hour is exclusive; an end before the start wraps past midnight and equal hours mean all day.
+    """
+    days: Tuple[str, ...]  # Empty for every day
+    start_hour: int
+    end_hour: int
+
+    @classmethod
+    def parse(cls, entry) -> "StationSchedule":
+        """Build a schedule from a config entry like {days: [mon, fri], start_hour: 6, end_hour: 10}. Raises ValueError."""
+        if not isinstance(entry, dict):
+            raise ValueError("expected `days`, `start_hour` and `end_hour`")
+        days = tuple(str(day).lower()[:3] for day in entry.get("days") or [])
+        unknown = [day for day in days if day not in WEEKDAYS]
+        if unknown:
+            raise ValueError(f"unknown day `{unknown[0]}` (expected: {', '.join(WEEKDAYS)})")
+        hours = (entry.get("start_hour"), entry.get("end_hour"))
+        if not all(isinstance(hour, int) and 0 <= hour <= 24 for hour in hours):
+            raise ValueError("`start_hour` and `end_hour` must be hours from 0 to 24")
+        return cls(days=days, start_hour=hours[0] % 24, end_hour=hours[1] % 24)
+
+    def hours(self) -> List[int]:
+        return [(self.start_hour + offset) % 24 for offset in range((self.end_hour - self.start_hour) % 24 or 24)]
+
+    def slots(self) -> Set[Tuple[str, int]]:
+        """Every (day, hour) the window covers"""
+        return {(day, hour) for day in self.days or WEEKDAYS for hour in self.hours()}
+
+    def matches(self, when: datetime.datetime) -> bool:
+        return (WEEKDAYS[when.weekday()], when.hour) in self.slots()
+
+    def __str__(self) -> str:
+        days = ", ".join(day.capitalize() for day in self.days) if self.days else "Daily"
+        return f"{days} {self.start_hour:02d}:00–{self.end_hour:02d}:00 UTC"
+
+def parse_schedule(station: dict) -> List[StationSchedule]:
+    """The valid schedule windows of a station; invalid ones are reported by validate_config"""
+    windows = []
+    for entry in station.get("schedule") or []:
+        try:
+            windows.append(StationSchedule.parse(entry))
+        except ValueError:
+            pass
+    return windows
+
+@dataclass
+class ConfigWarning:
+    """A problem with a station entry that does not prevent the bot from starting"""
+    station: str
+    message: str
+    is_error: bool = False  # Errors make the station unusable, warnings only degrade it
+    is_hint: bool = False  # Suggestions only; not logged on every load
+
+    def __str__(self) -> str:
+        return f"{self.station}: {self.message}"
+
+def validate_config(config: dict) -> List[ConfigWarning]:
+    """Check the station entries of a loaded config for non-fatal problems"""
+    warnings: List[ConfigWarning] = []
+    seen_names = {}
+    for name, station in (config.get("radios") or {}).items():
+        if not isinstance(station, dict) or not station.get("url"):
+            warnings.append(ConfigWarning(name, "missing `url`", is_error=True))
+            continue
+        if not is_valid_url(station["url"]):
+            warnings.append(ConfigWarning(name, f"invalid URL `{str(station['url'])[:80]}`", is_error=True))
+        elif station["url"].lower().startswith("http://") and not station.get("tls"):
+            warnings.append(ConfigWarning(name, "insecure `http://` URL; use `https://` if the station supports it"))
+        if station.get("thumbnail") and not is_valid_url(station["thumbnail"]):
+            warnings.append(ConfigWarning(name, f"invalid thumbnail URL `{str(station['thumbnail'])[:80]}`"))
+        if station.get("homepage"):
+            if not is_valid_url(station["homepage"]):
+                warnings.append(ConfigWarning(name, f"invalid homepage URL `{str(station['homepage'])[:80]}`"))
+            elif station["homepage"] == station["url"]:
+                warnings.append(ConfigWarning(name, "`homepage` is the stream URL"))
+        retry_urls = station.get("retry_urls")
+        if retry_urls is not None and not isinstance(retry_urls, list):
+            warnings.append(ConfigWarning(name, "`retry_urls` must be a list of mirror URLs"))
+        else:
+            for url in retry_urls or []:
+                if not is_valid_url(url):
+                    warnings.append(ConfigWarning(name, f"invalid retry URL `{str(url)[:80]}`"))
+        for tier, url in (station.get("quality_urls") or {}).items():
+            if tier not in QUALITY_TIERS:
+                warnings.append(ConfigWarning(name, f"unknown quality tier `{tier}` (expected: {', '.join(QUALITY_TIERS)})"))
+            elif not is_valid_url(url):
+                warnings.append(ConfigWarning(name, f"invalid `{tier}` quality URL `{str(url)[:80]}`"))
+        if "max_listeners" in station and (not isinstance(station["max_listeners"], int) or station["max_listeners"] < 1):
+            warnings.append(ConfigWarning(name, f"`max_listeners` must be a positive number, got `{station['max_listeners']}`"))
+        if station.get("country") and not (isinstance(station["country"], str) and len(station["country"]) == 2
+                                           and station["country"].isascii() and station["country"].isalpha()):
+            warnings.append(ConfigWarning(name, f"`country` must be a two-letter ISO code like `US`, got `{station['country']}`"))
+        if station.get("language") and not is_valid_language(station["language"]):
+            warnings.append(ConfigWarning(name, f"unknown `language` `{station['language']}` (expected a BCP 47 tag like `en` or `fr-CA`)"))
+        if station.get("bit_depth") and station["bit_depth"] not in (8, 16, 24, 32):
+            warnings.append(ConfigWarning(name, f"non-standard `bit_depth` `{station['bit_depth']}` (expected 8, 16, 24 or 32)"))
+        if "sample_rate" in station and (not isinstance(station["sample_rate"], int) or station["sample_rate"] < 1):
+            warnings.append(ConfigWarning(name, f"`sample_rate` must be a positive number in Hz, got `{station['sample_rate']}`"))
+        if "auth" in station and not (isinstance(station["auth"], dict) and station["auth"].get("username")
+                                      and station["auth"].get("password")):
+            warnings.append(ConfigWarning(name, "`auth` needs a `username` and a `password`"))
+        codec_args = station.get("codec_args")
+        if codec_args is not None and not (isinstance(codec_args, list) and all(isinstance(arg, str) for arg in codec_args)):
+            warnings.append(ConfigWarning(name, "`codec_args` must be a list of FFmpeg options like `[\"-f\", \"mpegts\"]`"))
+        elif codec_args and any(arg.startswith(UNSAFE_CODEC_ARGS) for arg in codec_args):
+            warnings.append(ConfigWarning(name, "`codec_args` may not set a proxy (`-http_proxy`, `-socks_proxy`); ignoring those options"))
+        emoji = station.get("discord_emoji")
+        if emoji and (not isinstance(emoji, str) or (emoji.startswith("<") and not CUSTOM_EMOJI_PATTERN.match(emoji))):
+            warnings.append(ConfigWarning(name, f"malformed `discord_emoji` `{emoji}` (expected an emoji or `<:name:id>`)"))
+        if "tags" in station and not station["tags"]:
+            warnings.append(ConfigWarning(name, "empty `tags`"))
+        if not station.get("description"):
+            warnings.append(ConfigWarning(name, "no `description`, so it can't be found by description search", is_hint=True))
+        # Names only differing in case are confusing in autocomplete
+        if name.lower() in seen_names:
+            warnings.append(ConfigWarning(name, f"name clashes with **{seen_names[name.lower()]}**"))
+        seen_names.setdefault(name.lower(), name)
+    # Station names win over aliases when resolving, so an alias equal to another station's name never matches
+    seen_aliases = {}
+    for name, station in (config.get("radios") or {}).items():
+        if not isinstance(station, dict):
+            continue
+        for alias in station.get("aliases") or []:
+            alias_lower = str(alias).lower()
+            if alias_lower == name.lower():
+                continue
+            if alias_lower in seen_names:
+                warnings.append(ConfigWarning(name, f"alias `{alias}` is the name of **{seen_names[alias_lower]}**"))
+            elif alias_lower in seen_aliases:
+                warnings.append(ConfigWarning(name, f"alias `{alias}` is also used by **{seen_aliases[alias_lower]}**"))
+            seen_aliases.setdefault(alias_lower, name)
+    # Overlapping schedules are resolved by the narrower window, which may not be what was intended
+    scheduled = {}
+    for name, station in (config.get("radios") or {}).items():
+        if not isinstance(station, dict) or not station.get("schedule"):
+            continue
+        if not isinstance(station["schedule"], list):
+            warnings.append(ConfigWarning(name, "`schedule` must be a list of time windows"))
+            continue
+        for entry in station["schedule"]:
+            try:
+                StationSchedule.parse(entry)
+            except ValueError as e:
+                warnings.append(ConfigWarning(name, f"invalid `schedule` entry: {e}"))
+        slots = set().union(*(window.slots() for window in parse_schedule(station)))
+        for other, other_slots in scheduled.items():
+            if slots & other_slots:
+                warnings.append(ConfigWarning(name, f"`schedule` overlaps with **{other}**"))
+        scheduled[name] = slots
+    return warnings
+
+ENV_PREFIX = "ALASTOR_"
+ENV_SEPARATOR = "__"
+
+UNSAFE_CODEC_ARGS = ("-http_proxy", "-socks_proxy")  # Would send stream traffic (and credentials) elsewhere
+
+def strip_unsafe_codec_args(args: List[str]) -> List[str]:
+    """Drop proxy options, and their value, from a station's codec_args"""
+    safe = []
+    skip_value = False
+    for arg in args:
+        if skip_value and not arg.startswith("-"):
+            skip_value = False
+            continue
+        skip_value = arg.startswith(UNSAFE_CODEC_ARGS)
+        if not skip_value:
+            safe.append(arg)
+    return safe
+
+DIFF_KEYS = ("url", "bitrate", "description")  # Station keys that count as a change when reloading
+
+@dataclass
+class ConfigDiff:
+    """Station names that were added, removed or changed between two configs"""
+    added: List[str] = field(default_factory=list)
+    removed: List[str] = field(default_factory=list)
+    changed: List[str] = field(default_factory=list)
+
+    def __bool__(self) -> bool:
+        return bool(self.added or self.removed or self.changed)
+
+def diff_configs(old: dict, new: dict) -> ConfigDiff:
+    """Compare the radios of two configs; a station changed if its url, bitrate or description differ"""
+    old_radios = old.get("radios") or {}
+    new_radios = new.get("radios") or {}
+    return ConfigDiff(
+        added=[name for name in new_radios if name not in old_radios],
+        removed=[name for name in old_radios if name not in new_radios],
+        changed=[
+            name for name, station in new_radios.items()
+            if name in old_radios and any(station.get(key) != old_radios[name].get(key) for key in DIFF_KEYS)
+        ]
+    )
+
+def apply_env_overrides(config: dict, environ=os.environ):
+    """
+    Override nested config values from ALASTOR_<SECTION>__<KEY> variables,
+    e.g. ALASTOR_BOT__AUTO_LEAVE_DELAY=60 sets bot.auto_leave_delay.
+    Values stay strings; load_config converts them like the BOT_* variables.
+    """
+    for name, value in environ.items():
+        if not name.startswith(ENV_PREFIX) or ENV_SEPARATOR not in name:
+            continue  # e.g. ALASTOR_STARTUP_RETRIES, which isn't a config value
+        path = name[len(ENV_PREFIX):].lower().split(ENV_SEPARATOR)
+        section = config
+        for key in path[:-1]:
+            section = section.setdefault(key, {})
+            if not isinstance(section, dict):
+                logger.warning(f"⚠️ Ignoring {name}: '{key}' is not a config section")
+                break
+        else:
+            section[path[-1]] = value
+            logger.debug(f"Config {'.'.join(path)} set from {name}")
+
+def warn_env_conflicts(environ=os.environ):
+    """The older BOT_<KEY> variables win over ALASTOR_BOT__<KEY>; say so when both are set to different values"""
+    bot_prefix = f"{ENV_PREFIX}BOT{ENV_SEPARATOR}"
+    for name, value in environ.items():
+        legacy_name = "BOT_" + name[len(bot_prefix):]
+        if name.startswith(bot_prefix) and environ.get(legacy_name, value) != value:
+            logger.warning(f"⚠️ Both {legacy_name} and {name} are set; using {legacy_name}")
+
+def read_config(path: str) -> dict:
+    """Parse the config file and apply the ALASTOR_* environment overrides, without checking or fixing anything"""
     with open(path, "r", encoding="utf-8") as f:
         config = yaml.safe_load(f)
+    apply_env_overrides(config)
+    return config
+
+def check_tls(config: dict):
+    """Stations marked tls: true must never be streamed unencrypted. Raises ConfigError otherwise."""
+    for name, station in (config.get("radios") or {}).items():
+        if isinstance(station, dict) and station.get("tls") and not str(station.get("url", "")).lower().startswith("https://"):
+            raise ConfigError(f"Station '{name}' has tls: true but its URL is not https://")
+        mirrors = station.get("retry_urls") if isinstance(station, dict) else None
+        if isinstance(mirrors, list) and station.get("tls") and not all(str(url).lower().startswith("https://") for url in mirrors):
+            raise ConfigError(f"Station '{name}' has tls: true but one of its retry_urls is not https://")
+
+def sanitize_stations(config: dict):
+    """Drop invalid optional station values (reported by validate_config), so the stations stay usable"""
+    for station in (config.get("radios") or {}).values():
+        if not isinstance(station, dict):
+            continue
+        if station.get("thumbnail") and not is_valid_url(station["thumbnail"]):
+            station["thumbnail"] = None
+        if station.get("homepage") and (not is_valid_url(station["homepage"]) or station["homepage"] == station.get("url")):
+            station["homepage"] = None
+        if "retry_urls" in station:
+            retry_urls = station["retry_urls"] if isinstance(station["retry_urls"], list) else []
+            station["retry_urls"] = [url for url in retry_urls if is_valid_url(url)]
+        if "max_listeners" in station and (not isinstance(station["max_listeners"], int) or station["max_listeners"] < 1):
+            station["max_listeners"] = None
+        if "sample_rate" in station and (not isinstance(station["sample_rate"], int) or station["sample_rate"] < 1):
+            station["sample_rate"] = None
+        if "codec_args" in station:
+            codec_args = station["codec_args"]
+            valid = isinstance(codec_args, list) and all(isinstance(arg, str) for arg in codec_args)
+            station["codec_args"] = strip_unsafe_codec_args(codec_args) if valid else None
+
+def load_config(path: str):
+    """Read the config file, fill in the bot defaults, then log and drop invalid station values"""
+    config = read_config(path)
+    check_tls(config)
+
+    warn_env_conflicts()
 
     # Überschreibe den Bot-Prefix, falls in der ENV gesetzt:
     bot_config = config.get("bot", {})
     bot_config["prefix"] = os.getenv("BOT_PREFIX", bot_config.get("prefix", "!"))
     bot_config["description"] = os.getenv("BOT_DESCRIPTION", bot_config.get("description",
                          "This bot can play various radio stations. It's inspired by Alastor from the 'Hazbin Hotel' series (Prime Video). Learn more: https://hazbinhotel.fandom.com/wiki/Alastor"))
+    legacy = os.getenv("BOT_LEGACY_COMMANDS", bot_config.get("legacy_commands", False))
+    bot_config["legacy_commands"] = str(legacy).lower() in ("1", "true", "yes")
+    bot_config["default_thumbnail"] = os.getenv("BOT_DEFAULT_THUMBNAIL", bot_config.get("default_thumbnail"))
+    if bot_config["default_thumbnail"] and not is_valid_url(bot_config["default_thumbnail"]):
+        logger.warning(f"⚠️ Invalid default_thumbnail URL, ignoring: {bot_config['default_thumbnail']}")
+        bot_config["default_thumbnail"] = None
+    bot_config["default_quality"] = os.getenv("BOT_DEFAULT_QUALITY", bot_config.get("default_quality", "high")).lower()
+    if bot_config["default_quality"] not in QUALITY_TIERS:
+        logger.warning(f"⚠️ Invalid default_quality '{bot_config['default_quality']}', using 'high'")
+        bot_config["default_quality"] = "high"
+    bot_config["failure_webhook"] = os.getenv("BOT_FAILURE_WEBHOOK", bot_config.get("failure_webhook"))
+    if bot_config["failure_webhook"] and not is_valid_url(bot_config["failure_webhook"]):
+        logger.warning("⚠️ Invalid failure_webhook URL, notifications disabled")
+        bot_config["failure_webhook"] = None
+    shards = os.getenv("BOT_SHARDS", bot_config.get("shards"))
+    try:
+        bot_config["shards"] = int(shards) if shards not in (None, "") else None
+    except ValueError:
+        logger.warning(f"⚠️ Invalid shard count, running unsharded: {shards}")
+        bot_config["shards"] = None
+    # The one shard this process runs when the shards are split across several processes
+    shard_id = os.getenv("BOT_SHARD_ID", bot_config.get("shard_id"))
+    try:
+        bot_config["shard_id"] = int(shard_id) if shard_id not in (None, "") else None
+    except ValueError:
+        bot_config["shard_id"] = -1
+    if bot_config["shard_id"] is not None and not (bot_config["shards"] and 0 <= bot_config["shard_id"] < bot_config["shards"]):
+        logger.warning(f"⚠️ Invalid shard_id (needs shards and must be below it), running all shards: {shard_id}")
+        bot_config["shard_id"] = None
+    interval = os.getenv("BOT_HEALTH_CHECK_INTERVAL", bot_config.get("health_check_interval", 600))
+    try:
+        bot_config["health_check_interval"] = max(int(interval), 0)
+    except ValueError:
+        logger.warning(f"⚠️ Invalid health_check_interval, using 600 seconds: {interval}")
+        bot_config["health_check_interval"] = 600
+    rotation = os.getenv("BOT_STATUS_ROTATION_SECS", bot_config.get("status_rotation_secs", 60))
+    try:
+        bot_config["status_rotation_secs"] = max(int(rotation), 0)
+    except ValueError:
+        logger.warning(f"⚠️ Invalid status_rotation_secs, using 60 seconds: {rotation}")
+        bot_config["status_rotation_secs"] = 60
+    bot_config["idle_status"] = os.getenv("BOT_IDLE_STATUS", bot_config.get("idle_status") or "for /radio play")
+    auto_disable = os.getenv("BOT_AUTO_DISABLE_AFTER", bot_config.get("auto_disable_after", 5))
+    try:
+        bot_config["auto_disable_after"] = max(int(auto_disable), 0)
+    except ValueError:
+        logger.warning(f"⚠️ Invalid auto_disable_after, using 5: {auto_disable}")
+        bot_config["auto_disable_after"] = 5
+    user_rate_limit = os.getenv("BOT_USER_RATE_LIMIT", bot_config.get("user_rate_limit_per_minute", 10))
+    try:
+        bot_config["user_rate_limit_per_minute"] = max(int(user_rate_limit), 0)
+    except ValueError:
+        logger.warning(f"⚠️ Invalid user_rate_limit_per_minute, using 10: {user_rate_limit}")
+        bot_config["user_rate_limit_per_minute"] = 10
+    component_cooldown = os.getenv("BOT_COMPONENT_COOLDOWN_MS", bot_config.get("component_cooldown_ms", 1000))
+    try:
+        bot_config["component_cooldown_ms"] = max(int(component_cooldown), 0)
+    except ValueError:
+        logger.warning(f"⚠️ Invalid component_cooldown_ms, using 1000: {component_cooldown}")
+        bot_config["component_cooldown_ms"] = 1000
+    sotd_hour = os.getenv("BOT_SOTD_HOUR_UTC", bot_config.get("sotd_hour_utc", 9))
+    try:
+        bot_config["sotd_hour_utc"] = int(sotd_hour) % 24
+    except ValueError:
+        logger.warning(f"⚠️ Invalid sotd_hour_utc, using 9: {sotd_hour}")
+        bot_config["sotd_hour_utc"] = 9
+    watchdog = os.getenv("BOT_WATCHDOG_ENABLED", bot_config.get("watchdog_enabled", True))
+    bot_config["watchdog_enabled"] = str(watchdog).lower() in ("1", "true", "yes")
+    watchdog_interval = os.getenv("BOT_WATCHDOG_INTERVAL", bot_config.get("watchdog_interval", 30))
+    try:
+        bot_config["watchdog_interval"] = max(int(watchdog_interval), 5)
+    except ValueError:
+        logger.warning(f"⚠️ Invalid watchdog_interval, using 30 seconds: {watchdog_interval}")
+        bot_config["watchdog_interval"] = 30
+    skip_ratio = os.getenv("BOT_SKIP_VOTE_RATIO", bot_config.get("skip_vote_ratio", 0.5))
+    try:
+        bot_config["skip_vote_ratio"] = min(max(float(skip_ratio), 0.0), 1.0)
+    except ValueError:
+        logger.warning(f"⚠️ Invalid skip_vote_ratio, using 0.5: {skip_ratio}")
+        bot_config["skip_vote_ratio"] = 0.5
+    auto_leave = os.getenv("BOT_AUTO_LEAVE_EMPTY", bot_config.get("auto_leave_empty", True))
+    bot_config["auto_leave_empty"] = str(auto_leave).lower() in ("1", "true", "yes")
+    delay = os.getenv("BOT_AUTO_LEAVE_DELAY", bot_config.get("auto_leave_delay", 30))
+    try:
+        bot_config["auto_leave_delay"] = max(int(delay), 0)
+    except ValueError:
+        logger.warning(f"⚠️ Invalid auto_leave_delay, using 30 seconds: {delay}")
+        bot_config["auto_leave_delay"] = 30
+    restore = os.getenv("BOT_RESTORE_ON_RESTART", bot_config.get("restore_on_restart", False))
+    bot_config["restore_on_restart"] = str(restore).lower() in ("1", "true", "yes")
+    bot_config["ytdlp_path"] = os.getenv("BOT_YTDLP_PATH", bot_config.get("ytdlp_path")) or None
+    bot_config["user_agent"] = os.getenv("BOT_USER_AGENT", bot_config.get("user_agent") or "alastor-bot/2.0")
+    health_port = os.getenv("BOT_HEALTH_PORT", bot_config.get("health_port", 8080))
+    try:
+        bot_config["health_port"] = int(health_port)
+    except ValueError:
+        logger.warning(f"⚠️ Invalid health_port, using 8080: {health_port}")
+        bot_config["health_port"] = 8080
+    # Reconnects of streams that ended (loop mode); set via ALASTOR_BOT__<KEY> like other nested values
+    for key, default, convert in (("max_stream_retries", 5, int), ("retry_base_delay_ms", 2000, int),
+                                  ("retry_max_delay_ms", 60000, int), ("retry_backoff_multiplier", 2.0, float)):
+        value = bot_config.get(key, default)
+        try:
+            bot_config[key] = max(convert(value), 0)
+        except (TypeError, ValueError):
+            logger.warning(f"⚠️ Invalid {key}, using {default}: {value}")
+            bot_config[key] = default
+    # Bot developer who passes all permission checks (falls back to the application owner)
+    owner_id = bot_config.get("owner_id")
+    try:
+        bot_config["owner_id"] = int(owner_id) if owner_id else None
+    except (TypeError, ValueError):
+        logger.warning(f"⚠️ Invalid owner_id, ignoring it: {owner_id}")
+        bot_config["owner_id"] = None
+    play_notification_ttl = os.getenv("BOT_PLAY_NOTIFICATION_TTL", bot_config.get("play_notification_ttl_secs", 30))
+    try:
+        bot_config["play_notification_ttl_secs"] = max(int(play_notification_ttl), 0)
+    except ValueError:
+        logger.warning(f"⚠️ Invalid play_notification_ttl_secs, using 30 seconds: {play_notification_ttl}")
+        bot_config["play_notification_ttl_secs"] = 30
+    # Seconds a user has to wait before running the same command again
+    cooldowns = {"radio play": 5, "radio stop": 5, "radio info": 2, "radio list": 3}
+    configured_cooldowns = bot_config.get("command_cooldowns")
+    if isinstance(configured_cooldowns, dict):
+        for command_name, seconds in configured_cooldowns.items():
+            try:
+                cooldowns[str(command_name)] = max(float(seconds), 0.0)
+            except (TypeError, ValueError):
+                logger.warning(f"⚠️ Invalid cooldown for /{command_name}, using the default: {seconds}")
+    elif configured_cooldowns is not None:
+        logger.warning("⚠️ command_cooldowns must map command names to seconds, using the defaults")
+    bot_config["command_cooldowns"] = cooldowns
+    join_announce = os.getenv("BOT_JOIN_ANNOUNCE", bot_config.get("join_announce", True))
+    bot_config["join_announce"] = str(join_announce).lower() in ("1", "true", "yes")
+    use_permissions = os.getenv("BOT_USE_COMMAND_PERMISSIONS", bot_config.get("use_command_permissions", False))
+    bot_config["use_command_permissions"] = str(use_permissions).lower() in ("1", "true", "yes")
+    bot_config["command_permissions_token"] = os.getenv("BOT_COMMAND_PERMISSIONS_TOKEN", bot_config.get("command_permissions_token"))
     config["bot"] = bot_config
 
-    return config
\ No newline at end of file
+    for warning in validate_config(config):
+        if warning.is_hint:
+            logger.debug(f"Station {warning}")
+        else:
+            logger.warning(f"⚠️ Station {warning}")
+    sanitize_stations(config)
+    return config
+
+_shared_config: Optional[dict] = None
+
+def set_config(config: dict):
+    """Share an already loaded config with every module (bot.py loads it with retries on startup)"""
+    global _shared_config
+    _shared_config = config
+
+def get_config() -> dict:
+    """
+    The config shared by all modules, so config.yaml is parsed (and its warnings logged) only once.
+    Loaded from CONFIG_PATH on first use if bot.py hasn't set it, e.g. in tests.
+    """
+    if _shared_config is None:
+        set_config(load_config(os.getenv("CONFIG_PATH", "config.yaml")))
+    return _shared_config
+
+def load_config_with_retry(path: str, max_attempts: int = 5, delay: float = 2, required_env: Iterable[str] = (),
+                           on_retry: Optional[Callable[[], None]] = None):
+    """
+    Load the config, retrying with exponential backoff while the file or required
+    environment variables are missing (e.g. secrets mounted after the container starts).
+    Invalid YAML is not retried. on_retry runs before each new attempt (e.g. to re-read .env).
+    """
+    for attempt in range(1, max_attempts + 1):
+        missing = []
+        try:
+            config = load_config(path)
+            missing = [name for name in required_env if not os.getenv(name)]
+            if missing:
+                raise ConfigError(f"Missing environment variables: {', '.join(missing)}")
+            return config
+        except (FileNotFoundError, ConfigError) as e:
+            # Mistakes in the file itself won't fix themselves by waiting
+            if attempt >= max_attempts or (isinstance(e, ConfigError) and not missing):
+                raise
+            wait = delay * 2 ** (attempt - 1)
+            logger.warning(f"⚠️ {e}. Retrying in {wait:.0f}s ({max_attempts - attempt} attempts left)")
+            time.sleep(wait)
+            if on_retry:
+                on_retry()
+
+def save_stations(path: str, changes: Dict[str, Optional[dict]]):
+    """
+    Write changed stations back to the config file, leaving all other entries as written.
+    changes maps a station name to the fields to set (a None value removes the field),
+    or to None to remove the station. ENV overrides and dropped invalid values are not baked in.
+    Note: YAML comments are not preserved.
+    """
+    with open(path, "r", encoding="utf-8") as f:
+        raw_config = yaml.safe_load(f) or {}
+    radios = raw_config.get("radios") or {}
+    for name, fields in changes.items():
+        if fields is None:
+            radios.pop(name, None)
+            continue
+        entry = radios.setdefault(name, {})
+        for key, value in fields.items():
+            if value is None:
+                entry.pop(key, None)
+            else:
+                entry[key] = value
+    raw_config["radios"] = radios
+    with open(path, "w", encoding="utf-8") as f:
+        yaml.safe_dump(raw_config, f, sort_keys=False, allow_unicode=True)
@@ -0,0 +1,118 @@
+from typing import Any, Iterable, List, Optional, Tuple
+
+class AlastorError(Exception):
+    """Base error carrying a user-facing embed title and description"""
+    title = "❌ Error"
+    user_facing = True  # False for internal errors that are only logged
+
+    def __init__(self, description: str, title: Optional[str] = None):
+        super().__init__(description)
+        self.description = description
+        if title:
+            self.title = title
+
+    def is_user_facing(self) -> bool:
+        return self.user_facing
+
+class VoiceConnectionError(AlastorError):
+    """Joining or moving to a voice channel failed"""
+    title = "❌ Connection Error"
+
+class StreamUnavailableError(AlastorError):
+    """A stream could not be resolved, reached or played"""
+    title = "❌ Stream Unavailable"
+
+class ConfigError(AlastorError):
+    """Required configuration is missing; only relevant at startup"""
+    title = "❌ Configuration Error"
+    user_facing = False
+
+class InsufficientPermissionsError(AlastorError):
+    """The bot lacks permissions in a channel"""
+    title = "❌ Missing Permissions"
+
+    def __init__(self, channel_id: int, missing: List[str]):
+        super().__init__(
+            f"I don't have permission to join or speak in <#{channel_id}>. "
+            f"Please grant me {' + '.join(missing)} permissions."
+        )
+        self.channel_id = channel_id
+        self.missing = missing
+
+class PlaylistParseError(AlastorError):
+    """A .pls/.m3u/.xspf playlist could not be parsed"""
+    title = "❌ Invalid Playlist"
+
+    def __init__(self, url: str, reason: str):
+        super().__init__(f"The station's playlist could not be read.\n\nReason: {reason}")
+        self.url = url
+        self.reason = reason
+
+class PermissionDeniedError(AlastorError):
+    """A user tried an action they are not allowed to perform"""
+    title = "❌ Permission Denied"
+
+    def __init__(self, user_id: int, action: str):
+        super().__init__(f"You are not allowed to {action}.")
+        self.user_id = user_id
+        self.action = action
+
+class StationDisabledError(AlastorError):
+    """A station was disabled by an admin or after repeated failed health checks"""
+    title = "⛔ Station Disabled"
+
+    def __init__(self, name: str):
+        super().__init__(f"**{name}** is currently disabled.\n\nAsk an admin to re-enable it with `/admin station enable`.")
+        self.name = name
+
+class StationRestrictedError(AlastorError):
+    """A station marked requires_dj was requested by someone without the DJ role"""
+    title = "🔒 Station Restricted"
+
+    def __init__(self, name: str):
+        super().__init__(f"**{name}** is restricted. You need the DJ role to play it.")
+        self.name = name
+
+class ListenerLimitError(AlastorError):
+    """The voice channel already has as many listeners as the station's max_listeners allows"""
+    title = "🚷 Listener Limit Reached"
+
+    def __init__(self, name: str, listeners: int, limit: int):
+        super().__init__(f"This station limits listeners to {limit}. The channel already has {listeners} people.")
+        self.name = name
+        self.listeners = listeners
+        self.limit = limit
+
+class MultiError(AlastorError):
+    """Several errors of a bulk operation (e.g. stopping many servers), reported together"""
+    title = "❌ Multiple Errors"
+
+    def __init__(self, errors: List[AlastorError]):
+        super().__init__("\n".join(f"{index}. {error.description}" for index, error in enumerate(errors, 1)))
+        self.errors = errors
+
+    def is_user_facing(self) -> bool:
+        return any(error.is_user_facing() for error in self.errors)
+
+def collect_errors(results: Iterable[Any]) -> Tuple[List[Any], Optional[AlastorError]]:
+    """
+    Split results of e.g. asyncio.gather(..., return_exceptions=True) into the successful values
+    and one error: None, the only error, or a MultiError. Other exceptions become internal AlastorErrors.
+    """
+    values, errors = [], []
+    for result in results:
+        if isinstance(result, AlastorError):
+            errors.append(result)
+        elif isinstance(result, Exception):
+            error = AlastorError(str(result) or type(result).__name__)
+            error.user_facing = False
+            errors.append(error)
+        else:
+            values.append(result)
+    if not errors:
+        return values, None
+    return values, errors[0] if len(errors) == 1 else MultiError(errors)
+
+def is_user_facing(error: Exception) -> bool:
+    """Whether an error should be shown to the user (otherwise it is only logged)"""
+    return isinstance(error, AlastorError) and error.is_user_facing()
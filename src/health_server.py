@@ -0,0 +1,48 @@
+import logging
+from aiohttp import web
+import sys
+from pathlib import Path
+
+# Add the parent directory to sys.path to allow imports
+sys.path.insert(0, str(Path(__file__).parent.parent))
+
+from src.commands.radio import get_all_active_streams
+
+logger = logging.getLogger(__name__)
+
+# Set once the gateway connection is up and the slash commands are synced
+is_ready = False
+
+def set_ready(ready: bool = True):
+    global is_ready
+    is_ready = ready
+
+def build_app(bot) -> web.Application:
+    """
+    GET /health: 200 while the process is alive, with a few numbers for dashboards
+    GET /ready: 200 once the bot is connected to Discord, 503 before (for Kubernetes readiness probes)
+    """
+    async def health(request: web.Request) -> web.Response:
+        return web.json_response({
+            "status": "ok",
+            "guilds": len(bot.guilds),
+            "active_streams": len(get_all_active_streams())
+        })
+
+    async def ready(request: web.Request) -> web.Response:
+        if is_ready:
+            return web.json_response({"status": "ready"})
+        return web.json_response({"status": "starting"}, status=503)
+
+    app = web.Application()
+    app.router.add_get("/health", health)
+    app.router.add_get("/ready", ready)
+    return app
+
+async def start_health_server(bot, port: int) -> web.AppRunner:
+    """Serve the probe endpoints on all interfaces. Stop the returned runner with cleanup()."""
+    runner = web.AppRunner(build_app(bot), access_log=None)
+    await runner.setup()
+    await web.TCPSite(runner, "0.0.0.0", port).start()
+    logger.info(f"🩺 Health endpoints listening on port {port} (/health, /ready)")
+    return runner
@@ -0,0 +1,43 @@
+import logging
+import aiohttp
+from typing import Optional
+import sys
+from pathlib import Path
+
+# Add the parent directory to sys.path to allow imports
+sys.path.insert(0, str(Path(__file__).parent.parent))
+
+from src.config import get_config
+
+logger = logging.getLogger(__name__)
+
+# Configuration loaded by bot.py
+config = get_config()
+USER_AGENT = config.get("bot", {}).get("user_agent", "alastor-bot/2.0")
+MAX_CONNECTIONS_PER_HOST = 4
+REQUEST_TIMEOUT = 10  # Seconds, per request
+MAX_REDIRECTS = 5  # Streams often redirect to a CDN; more hops than this is most likely a loop
+
+_session: Optional[aiohttp.ClientSession] = None
+
+def get_session() -> aiohttp.ClientSession:
+    """
+    Shared session for health checks, playlist downloads and API calls, so connections are reused.
+    Created on first use because aiohttp sessions must be created inside the running event loop.
+    """
+    global _session
+    if _session is None or _session.closed:
+        _session = aiohttp.ClientSession(
+            connector=aiohttp.TCPConnector(limit_per_host=MAX_CONNECTIONS_PER_HOST),
+            timeout=aiohttp.ClientTimeout(total=REQUEST_TIMEOUT),
+            headers={"User-Agent": USER_AGENT}
+        )
+        logger.debug(f"Created shared HTTP session (User-Agent: {USER_AGENT})")
+    return _session
+
+async def close_session():
+    """Close the shared session, e.g. on shutdown"""
+    global _session
+    if _session is not None and not _session.closed:
+        await _session.close()
+    _session = None
@@ -0,0 +1,134 @@
+import re
+import asyncio
+import logging
+import aiohttp
+from dataclasses import dataclass
+from typing import Awaitable, Callable, Optional
+
+logger = logging.getLogger(__name__)
+
+STREAM_TITLE_PATTERN = re.compile(r"StreamTitle='(.*?)';", re.DOTALL)
+STREAM_URL_PATTERN = re.compile(r"StreamUrl='(.*?)';", re.DOTALL)
+
+V1_BLOCK_SIZE = 16  # The length byte counts 16-byte units
+V2_BLOCK_SIZE = 32  # SHOUTcast v2 aligns metadata blocks to 32 bytes
+MAX_BITRATE_SAMPLE_BYTES = 10 * 1024 * 1024  # Stop measuring after 10 MB, whatever the duration
+
+@dataclass
+class IcyMetadata:
+    title: Optional[str] = None
+    url: Optional[str] = None
+
+def _match(pattern: re.Pattern, text: str) -> Optional[str]:
+    match = pattern.search(text)
+    return match.group(1).strip() or None if match else None
+
+def parse_stream_title(metadata: bytes) -> Optional[str]:
+    """Extract StreamTitle from an ICY metadata block (null-padded)"""
+    text = metadata.rstrip(b"\0").decode("utf-8", errors="replace")
+    return _match(STREAM_TITLE_PATTERN, text)
+
+def parse_icy_v2_metadata_block(metadata: bytes) -> IcyMetadata:
+    """
+    Parse a SHOUTcast v2 (ICY 2.0) metadata block: StreamTitle and StreamUrl as UTF-16 text
+    (little-endian unless a byte order mark says otherwise), null-padded to the 32-byte alignment.
+    """
+    if metadata[:2] in (b"\xff\xfe", b"\xfe\xff"):
+        text = metadata.decode("utf-16", errors="replace")
+    else:
+        text = metadata.decode("utf-16-le", errors="replace")
+    text = text.rstrip("\0")
+    return IcyMetadata(title=_match(STREAM_TITLE_PATTERN, text), url=_match(STREAM_URL_PATTERN, text))
+
+def detect_icy_version(headers) -> int:
+    """
+    2 for SHOUTcast v2 servers, which send X-ICY-Protocol: 2, otherwise 1.
+    icy-pub and icy-url alone don't tell: Icecast and SHOUTcast v1 send them too.
+    """
+    return 2 if headers.get("x-icy-protocol", "").strip() == "2" else 1
+
+async def measure_stream_bitrate(url: str, session: aiohttp.ClientSession, sample_duration_ms: int,
+                                 auth: Optional[aiohttp.BasicAuth] = None) -> Optional[int]:
+    """
+    Estimate a stream's bitrate in kbps by counting the bytes received for sample_duration_ms.
+    Servers that send a burst on connect make the estimate a bit high.
+    Returns None if nothing was received. Raises aiohttp errors if the request fails.
+    """
+    received = 0
+    async with session.get(url, auth=auth) as response:
+        response.raise_for_status()
+        loop = asyncio.get_running_loop()
+        start = loop.time()
+        deadline = start + sample_duration_ms / 1000
+        while received < MAX_BITRATE_SAMPLE_BYTES:
+            remaining = deadline - loop.time()
+            if remaining <= 0:
+                break
+            try:
+                chunk = await asyncio.wait_for(response.content.readany(), timeout=remaining)
+            except asyncio.TimeoutError:
+                break
+            if not chunk:
+                break  # The server closed the stream
+            received += len(chunk)
+        elapsed = loop.time() - start
+    if not received or elapsed <= 0:
+        return None
+    return round(received * 8 / elapsed / 1000)
+
+class IcyReader:
+    """
+    Reads in-stream ICY metadata over a second connection to the stream.
+    The server sends a metadata block every icy-metaint bytes of audio:
+    one length byte (x16) followed by e.g. "StreamTitle='Artist - Song';".
+    SHOUTcast v2 servers count the length in 32-byte units and send UTF-16 text.
+    """
+
+    def __init__(self, url: str, on_title: Optional[Callable[[str], Awaitable[None]]] = None,
+                 auth: Optional[aiohttp.BasicAuth] = None):
+        self.url = url
+        self.on_title = on_title
+        self.auth = auth  # For password-protected streams
+        self.title: Optional[str] = None
+        self.task: Optional[asyncio.Task] = None
+
+    def start(self):
+        self.task = asyncio.create_task(self._run())
+
+    def stop(self):
+        if self.task and not self.task.done():
+            self.task.cancel()
+
+    async def _run(self):
+        try:
+            async with aiohttp.ClientSession(
+                timeout=aiohttp.ClientTimeout(total=None, sock_connect=10, sock_read=60),
+                headers={"Icy-MetaData": "1", "User-Agent": "Mozilla/5.0"}
+            ) as session:
+                async with session.get(self.url, auth=self.auth) as response:
+                    metaint = response.headers.get("icy-metaint")
+                    if not metaint or not metaint.isdigit():
+                        logger.debug(f"No ICY metadata offered by {self.url}")
+                        return
+                    version = detect_icy_version(response.headers)
+                    logger.debug(f"Reading ICY v{version} metadata from {self.url}")
+                    await self._read_metadata(response.content, int(metaint), version)
+        except asyncio.CancelledError:
+            raise
+        except Exception as e:
+            logger.debug(f"ICY metadata reader for {self.url} stopped: {e}")
+
+    async def _read_metadata(self, stream: aiohttp.StreamReader, metaint: int, version: int = 1):
+        block_size = V2_BLOCK_SIZE if version == 2 else V1_BLOCK_SIZE
+        while True:
+            await stream.readexactly(metaint)  # Skip the audio data
+            length = (await stream.readexactly(1))[0] * block_size
+            if not length:
+                continue
+            block = await stream.readexactly(length)
+            title = parse_icy_v2_metadata_block(block).title if version == 2 else parse_stream_title(block)
+            if title and title != self.title:
+                self.title = title
+                logger.info(f"🎶 Now playing: {title}")
+                if self.on_title:
+                    await self.on_title(title)
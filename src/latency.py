@@ -0,0 +1,48 @@
+import time
+import math
+from collections import deque
+from typing import Deque, Dict, List, Tuple
+
+MAX_SAMPLES = 100  # Per command
+
+class CommandLatencies:
+    """The most recent handler durations (in seconds) per slash command"""
+
+    def __init__(self, max_samples: int = MAX_SAMPLES):
+        self.max_samples = max_samples
+        self.samples: Dict[str, Deque[float]] = {}  # qualified command name -> durations
+
+    def record(self, command_name: str, seconds: float):
+        self.samples.setdefault(command_name, deque(maxlen=self.max_samples)).append(seconds)
+
+    def record_since(self, command_name: str, started_at: float):
+        """Record the time since a time.perf_counter() value"""
+        self.record(command_name, time.perf_counter() - started_at)
+
+    def mean(self, command_name: str) -> float:
+        samples = self.samples.get(command_name)
+        return sum(samples) / len(samples) if samples else 0.0
+
+    def percentiles(self, command_name: str, quantiles: Tuple[float, ...] = (0.5, 0.95, 0.99)) -> List[float]:
+        """Nearest-rank percentiles of the stored samples (at most max_samples, so sorting is cheap)"""
+        ordered = sorted(self.samples.get(command_name, ()))
+        if not ordered:
+            return []
+        return [ordered[max(math.ceil(q * len(ordered)) - 1, 0)] for q in quantiles]
+
+    def summary(self) -> List[Tuple[str, int, List[float]]]:
+        """(command, sample count, [p50, p95, p99]) for every command, slowest p95 first"""
+        rows = [(name, len(samples), self.percentiles(name)) for name, samples in self.samples.items() if samples]
+        return sorted(rows, key=lambda row: -row[2][1])
+
+    def reset(self):
+        self.samples.clear()
+
+class HealthCheckLatencies(CommandLatencies):
+    """The most recent health check request durations per station, kept apart for failed checks"""
+
+    def record_since(self, station_name: str, started_at: float, failed: bool = False):
+        super().record_since(f"{station_name} (failed)" if failed else station_name, started_at)
+
+command_latencies = CommandLatencies()
+health_check_latencies = HealthCheckLatencies()
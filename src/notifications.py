@@ -0,0 +1,57 @@
+import time
+import logging
+from typing import Dict, Optional
+import sys
+from pathlib import Path
+
+# Add the parent directory to sys.path to allow imports
+sys.path.insert(0, str(Path(__file__).parent.parent))
+
+from src.config import get_config
+from src.http_client import get_session
+
+logger = logging.getLogger(__name__)
+
+# Configuration loaded by bot.py
+config = get_config()
+FAILURE_WEBHOOK = config.get("bot", {}).get("failure_webhook")
+NOTIFY_INTERVAL = 300  # At most one notification per station every 5 minutes
+
+last_notified: Dict[str, float] = {}  # station_name -> timestamp of last webhook call
+
+async def notify_stream_failure(station_name: str, url: str, guild_id: Optional[int] = None, reason: str = ""):
+    """Post a stream failure embed to the configured Discord webhook"""
+    if not FAILURE_WEBHOOK:
+        return
+
+    now = time.time()
+    if now - last_notified.get(station_name, 0) < NOTIFY_INTERVAL:
+        logger.debug(f"Skipping failure notification for '{station_name}' (rate limited)")
+        return
+    last_notified[station_name] = now
+
+    fields = [{"name": "URL", "value": url[:1024], "inline": False}]
+    if guild_id is not None:
+        fields.append({"name": "Server ID", "value": str(guild_id), "inline": True})
+    if reason:
+        fields.append({"name": "Reason", "value": reason[:1024], "inline": False})
+
+    payload = {
+        "username": "Alastor - The Radio Daemon",
+        "embeds": [{
+            "title": "📡 Stream Failure",
+            "description": f"**{station_name}** failed or dropped.",
+            "color": 0xE74C3C,
+            "fields": fields,
+            "footer": {"text": "Alastor - The Radio Daemon"}
+        }]
+    }
+
+    try:
+        async with get_session().post(FAILURE_WEBHOOK, json=payload) as response:
+            if response.status >= 400:
+                logger.warning(f"⚠️ Failure webhook returned HTTP {response.status}")
+            else:
+                logger.info(f"📨 Sent failure notification for '{station_name}'")
+    except Exception as e:
+        logger.error(f"❌ Failed to send failure notification: {e}")
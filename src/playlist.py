@@ -0,0 +1,249 @@
+import re
+import logging
+import aiohttp
+import xml.etree.ElementTree as ElementTree
+from dataclasses import dataclass, field
+from typing import Dict, List, Optional, Tuple
+
+logger = logging.getLogger(__name__)
+
+PLAYLIST_EXTENSIONS = {
+    ".m3u": "m3u",
+    ".m3u8": "m3u",
+    ".pls": "pls",
+    ".xspf": "xspf",
+    ".asx": "asx"
+}
+
+# For playlist URLs without a file extension
+PLAYLIST_CONTENT_TYPES = {
+    "audio/x-mpegurl": "m3u",
+    "audio/mpegurl": "m3u",
+    "application/x-mpegurl": "m3u",
+    "application/vnd.apple.mpegurl": "m3u",
+    "audio/x-scpls": "pls",
+    "application/pls+xml": "pls",
+    "application/xspf+xml": "xspf",
+    "video/x-ms-asf": "asx",
+    "video/x-ms-asx": "asx",
+    "audio/x-ms-asx": "asx"
+}
+
+@dataclass
+class PlsEntry:
+    url: str
+    title: Optional[str] = None
+    length: Optional[int] = None  # -1 marks a live stream
+
+EXTINF_ATTRIBUTE_PATTERN = re.compile(r'([\w-]+)="([^"]*)"')
+
+@dataclass
+class ExtinfEntry:
+    url: str
+    title: Optional[str] = None
+    duration: Optional[int] = None  # -1 marks a live stream
+    attributes: Dict[str, str] = field(default_factory=dict)  # e.g. tvg-logo, tvg-bitrate
+
+def parse_m3u_extended(content: str) -> List[ExtinfEntry]:
+    """
+    Parse an (extended) M3U playlist.
+    "#EXTINF:<duration>,<title>" lines attach metadata to the URL that follows them.
+    """
+    entries: List[ExtinfEntry] = []
+    duration: Optional[int] = None
+    title: Optional[str] = None
+    attributes: Dict[str, str] = {}
+
+    for line in content.splitlines():
+        line = line.strip()
+        if not line:
+            continue
+        if line.upper().startswith("#EXTINF:"):
+            info, _, raw_title = line[len("#EXTINF:"):].partition(",")
+            # Attributes like tvg-logo="..." may follow the duration
+            try:
+                duration = int(info.split()[0]) if info.split() else None
+            except ValueError:
+                duration = None
+            title = raw_title.strip() or None
+            attributes = dict(EXTINF_ATTRIBUTE_PATTERN.findall(info))
+            continue
+        if line.startswith("#"):
+            continue
+        if line.startswith(("http://", "https://")):
+            entries.append(ExtinfEntry(url=line, title=title, duration=duration, attributes=attributes))
+        duration, title, attributes = None, None, {}
+
+    return entries
+
+def select_m3u_entry(entries: List[ExtinfEntry]) -> Optional[ExtinfEntry]:
+    """Prefer the first live stream (duration -1), otherwise the first entry"""
+    if not entries:
+        return None
+    return next((entry for entry in entries if entry.duration == -1), entries[0])
+
+def parse_pls(content: str) -> List[PlsEntry]:
+    """
+    Parse a PLS playlist ([playlist] header with FileN/TitleN/LengthN keys).
+    Entries are returned in playlist order. Raises ValueError if no entries are found.
+    """
+    files: Dict[int, str] = {}
+    titles: Dict[int, str] = {}
+    lengths: Dict[int, int] = {}
+
+    for line in content.splitlines():
+        line = line.strip()
+        if not line or line.startswith(("[", ";", "#")) or "=" not in line:
+            continue
+        key, value = line.split("=", 1)
+        key, value = key.strip(), value.strip()
+        lower_key = key.lower()
+
+        for prefix, target in (("file", files), ("title", titles), ("length", lengths)):
+            if lower_key.startswith(prefix) and lower_key[len(prefix):].isdigit():
+                index = int(lower_key[len(prefix):])
+                if target is lengths:
+                    try:
+                        target[index] = int(value)
+                    except ValueError:
+                        pass
+                else:
+                    target[index] = value
+                break
+
+    entries = [
+        PlsEntry(url=files[index], title=titles.get(index), length=lengths.get(index))
+        for index in sorted(files)
+        if files[index].startswith(("http://", "https://"))
+    ]
+    if not entries:
+        raise ValueError("PLS playlist contains no stream entries")
+    return entries
+
+def select_pls_entry(entries: List[PlsEntry]) -> PlsEntry:
+    """Prefer the first live stream (Length=-1), otherwise the first entry"""
+    return next((entry for entry in entries if entry.length == -1), entries[0])
+
+def _local_name(tag: str) -> str:
+    """Strip the XML namespace ("{http://xspf.org/ns/0/}track" -> "track")"""
+    return tag.rsplit("}", 1)[-1]
+
+def parse_xspf(content: str) -> List[str]:
+    """
+    Parse an XSPF playlist (<playlist><trackList><track><location>).
+    Returns the http(s) locations in playlist order. Raises ValueError for
+    malformed XML, a missing <trackList> or a playlist without stream entries.
+    """
+    try:
+        root = ElementTree.fromstring(content)
+    except ElementTree.ParseError as e:
+        raise ValueError(f"Invalid XSPF playlist: {e}")
+
+    track_list = next((child for child in root if _local_name(child.tag) == "trackList"), None)
+    if _local_name(root.tag) != "playlist" or track_list is None:
+        raise ValueError("XSPF playlist has no <trackList>")
+
+    urls = [
+        location.text.strip()
+        for track in track_list if _local_name(track.tag) == "track"
+        for location in track if _local_name(location.tag) == "location"
+        if location.text and location.text.strip().startswith(("http://", "https://"))
+    ]
+    if not urls:
+        raise ValueError("XSPF playlist contains no stream entries")
+    return urls
+
+def parse_asx(content: str) -> List[str]:
+    """
+    Parse an ASX playlist (<asx><entry><ref href="..."/></entry></asx>).
+    Tag and attribute names are case-insensitive in ASX. Returns the http(s) and
+    mms references in playlist order, with mms:// rewritten to mmsh:// for FFmpeg.
+    Raises ValueError if none are found.
+    """
+    try:
+        root = ElementTree.fromstring(content)
+    except ElementTree.ParseError as e:
+        raise ValueError(f"Invalid ASX playlist: {e}")
+
+    urls = []
+    for element in root.iter():
+        if _local_name(element.tag).lower() != "ref":
+            continue
+        href = next((value for key, value in element.attrib.items() if key.lower() == "href"), "").strip()
+        if href.lower().startswith("mms://"):
+            # FFmpeg only speaks MMS over HTTP
+            href = "mmsh://" + href[len("mms://"):]
+        if href.lower().startswith(("http://", "https://", "mmsh://")):
+            urls.append(href)
+    if not urls:
+        raise ValueError("ASX playlist contains no <Ref> stream entries")
+    return urls
+
+def detect_playlist_type(url: str, content_type: Optional[str] = None) -> Optional[str]:
+    """Detect the playlist format from the URL extension or, failing that, the Content-Type"""
+    path = url.lower().split("?", 1)[0]
+    for extension, kind in PLAYLIST_EXTENSIONS.items():
+        if path.endswith(extension):
+            return kind
+    if content_type:
+        return PLAYLIST_CONTENT_TYPES.get(content_type.split(";")[0].strip().lower())
+    return None
+
+def parse_playlist(kind: str, content: str) -> Tuple[str, Optional[str]]:
+    """Parse a playlist of the given type. Returns (stream URL, title). Raises ValueError if unusable."""
+    if kind == "pls":
+        entry = select_pls_entry(parse_pls(content))
+        return entry.url, entry.title
+    if kind == "m3u":
+        entry = select_m3u_entry(parse_m3u_extended(content))
+        if entry is None:
+            raise ValueError("M3U playlist contains no stream entries")
+        return entry.url, entry.title
+    if kind == "xspf":
+        return parse_xspf(content)[0], None
+    if kind == "asx":
+        return parse_asx(content)[0], None
+    raise ValueError(f"Unsupported playlist type: {kind}")
+
+def describe_redirects(response: aiohttp.ClientResponse) -> Optional[str]:
+    """Redirect chain like "a -> b -> c" for debug logs, or None if the request was not redirected"""
+    if not response.history:
+        return None
+    return " -> ".join([str(step.url) for step in response.history] + [str(response.url)])
+
+async def resolve_stream_url(url: str, session: aiohttp.ClientSession, max_redirects: int = 10,
+                             auth: Optional[aiohttp.BasicAuth] = None) -> Tuple[str, Optional[str]]:
+    """
+    Resolve a station URL to the actual stream URL.
+    Playlists are detected by extension or Content-Type and parsed; other URLs resolve to where they redirect to.
+    Returns (stream URL, title). Raises ValueError for unparseable playlists and aiohttp errors for network failures.
+    """
+    kind = detect_playlist_type(url)
+    if kind is None:
+        # Only ask for the headers, so a plain audio stream isn't opened just to find out what it is
+        try:
+            async with session.head(url, allow_redirects=True, max_redirects=max_redirects, auth=auth) as response:
+                response.raise_for_status()
+                redirects = describe_redirects(response)
+                if redirects:
+                    logger.debug(f"Redirected: {redirects}")
+                kind = detect_playlist_type(str(response.url), response.headers.get("content-type"))
+                if kind is None:
+                    return str(response.url), None
+        except aiohttp.ClientError as e:
+            # Some stream servers (e.g. SHOUTcast v1) don't answer HEAD requests
+            logger.debug(f"HEAD {url} failed, falling back to GET: {e!r}")
+
+    async with session.get(url, max_redirects=max_redirects, auth=auth) as response:
+        response.raise_for_status()
+        redirects = describe_redirects(response)
+        if redirects:
+            logger.debug(f"Redirected: {redirects}")
+        kind = kind or detect_playlist_type(str(response.url), response.headers.get("content-type"))
+        if kind is None:
+            # A plain audio stream; don't download it
+            return str(response.url), None
+        text = await response.text()
+
+    logger.debug(f"Resolving {kind} playlist {url}")
+    return parse_playlist(kind, text)
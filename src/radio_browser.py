@@ -0,0 +1,65 @@
+import time
+import asyncio
+import logging
+from dataclasses import dataclass
+from typing import List, Optional
+
+from src.http_client import get_session
+
+logger = logging.getLogger(__name__)
+
+API_BASE = "https://de1.api.radio-browser.info/json"
+MIN_REQUEST_INTERVAL = 2  # Seconds between API calls, to be nice to the public endpoint
+
+_request_lock = asyncio.Lock()
+_last_request = 0.0
+
+@dataclass
+class BrowserStation:
+    stationuuid: str
+    name: str
+    url_resolved: str
+    codec: str = ""
+    bitrate: int = 0
+    country: str = ""
+    tags: str = ""
+    language: str = ""  # First language code, e.g. "en"
+
+    @classmethod
+    def from_json(cls, data: dict) -> "BrowserStation":
+        return cls(
+            stationuuid=data.get("stationuuid", ""),
+            name=(data.get("name") or "").strip(),
+            url_resolved=data.get("url_resolved") or data.get("url") or "",
+            codec=data.get("codec") or "",
+            bitrate=data.get("bitrate") or 0,
+            country=data.get("country") or "",
+            tags=data.get("tags") or "",
+            # "languagecodes" holds ISO codes ("en,de"), "language" the names ("english,german")
+            language=((data.get("languagecodes") or data.get("language") or "").split(",")[0]).strip()
+        )
+
+async def _get(path: str, params: dict) -> list:
+    """Rate-limited GET request against the Radio Browser API"""
+    global _last_request
+    async with _request_lock:
+        wait = MIN_REQUEST_INTERVAL - (time.monotonic() - _last_request)
+        if wait > 0:
+            await asyncio.sleep(wait)
+        try:
+            async with get_session().get(f"{API_BASE}/{path}", params=params) as response:
+                response.raise_for_status()
+                return await response.json(content_type=None)
+        finally:
+            _last_request = time.monotonic()
+
+async def search_stations(query: str, limit: int = 10) -> List[BrowserStation]:
+    """Search Radio Browser stations by name"""
+    data = await _get("stations/search", {"name": query, "limit": limit, "hidebroken": "true"})
+    logger.info(f"📡 Radio Browser search '{query}' returned {len(data)} stations")
+    return [BrowserStation.from_json(item) for item in data]
+
+async def get_station(stationuuid: str) -> Optional[BrowserStation]:
+    """Fetch a single Radio Browser station by UUID"""
+    data = await _get(f"stations/byuuid/{stationuuid}", {})
+    return BrowserStation.from_json(data[0]) if data else None
@@ -0,0 +1,89 @@
+import time
+from collections import deque
+from typing import Deque, Dict, Optional, Tuple
+
+class RateLimiter:
+    """Sliding-window rate limiter keyed by an ID (e.g. a user ID)"""
+
+    def __init__(self, limit: int, window: float = 60):
+        self.limit = limit
+        self.window = window
+        self.hits: Dict[int, Deque[float]] = {}  # key -> timestamps of recent requests
+
+    def hit(self, key: int) -> Optional[float]:
+        """
+        Register a request. Returns None if it is allowed, otherwise the
+        number of seconds until the next request will be allowed.
+        """
+        now = time.monotonic()
+        hits = self.hits.setdefault(key, deque())
+        while hits and now - hits[0] >= self.window:
+            hits.popleft()
+        if len(hits) >= self.limit:
+            return self.window - (now - hits[0])
+        hits.append(now)
+        return None
+
+    def prune(self, max_idle: float = 3600) -> int:
+        """Forget keys without requests in the last max_idle seconds. Returns the number removed."""
+        now = time.monotonic()
+        idle = [key for key, hits in self.hits.items() if not hits or now - hits[-1] >= max_idle]
+        for key in idle:
+            del self.hits[key]
+        return len(idle)
+
+class CommandCooldown:
+    """Minimum time between two uses of the same command by the same user"""
+
+    def __init__(self, cooldowns: Dict[str, float]):
+        self.cooldowns = cooldowns  # qualified command name -> seconds
+        self.last_used: Dict[Tuple[int, str], float] = {}  # (user ID, command) -> time of the last use
+
+    def hit(self, user_id: int, command_name: str) -> Optional[float]:
+        """
+        Register a use. Returns None if it is allowed, otherwise the
+        number of seconds until the user may run the command again.
+        """
+        cooldown = self.cooldowns.get(command_name)
+        if not cooldown:
+            return None
+        now = time.monotonic()
+        last_used = self.last_used.get((user_id, command_name))
+        if last_used is not None and now - last_used < cooldown:
+            return cooldown - (now - last_used)
+        self.last_used[(user_id, command_name)] = now
+        return None
+
+    def prune(self) -> int:
+        """Forget uses whose cooldown has expired. Returns the number removed."""
+        now = time.monotonic()
+        expired = [key for key, last_used in self.last_used.items() if now - last_used >= self.cooldowns.get(key[1], 0)]
+        for key in expired:
+            del self.last_used[key]
+        return len(expired)
+
+class ComponentCooldown:
+    """Ignores repeated presses of the same button (or select menu) by the same user"""
+
+    def __init__(self, cooldown: float):
+        self.cooldown = cooldown  # Seconds
+        self.last_pressed: Dict[Tuple[int, str], float] = {}  # (user ID, custom ID) -> time of the last press
+
+    def hit(self, user_id: int, custom_id: str) -> bool:
+        """Register a press. Returns False if the same component was pressed less than cooldown seconds ago."""
+        if not self.cooldown:
+            return True
+        now = time.monotonic()
+        last_pressed = self.last_pressed.get((user_id, custom_id))
+        if last_pressed is not None and now - last_pressed < self.cooldown:
+            return False
+        self.last_pressed[(user_id, custom_id)] = now
+        return True
+
+    def prune(self) -> int:
+        """Forget presses whose cooldown has expired. Returns the number removed."""
+        now = time.monotonic()
+        expired = [key for key, last_pressed in self.last_pressed.items() if now - last_pressed >= self.cooldown]
+        for key in expired:
+            del self.last_pressed[key]
+        return len(expired)
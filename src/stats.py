@@ -0,0 +1,73 @@
+from typing import Dict, List, Optional, Tuple
+
+class StatsTracker:
+    """Per-guild playback statistics (plays, listening time, stations and DJs)"""
+
+    def __init__(self):
+        self.guilds: Dict[int, Dict] = {}  # guild_id -> {total_plays, total_seconds, station_counts, user_counts}
+        self.global_station_counts: Dict[str, int] = {}  # station_name -> plays on all servers (kept on guild resets)
+
+    def _get(self, guild_id: int) -> Dict:
+        if guild_id not in self.guilds:
+            self.guilds[guild_id] = {
+                "total_plays": 0,
+                "total_seconds": 0,
+                "station_counts": {},
+                "user_counts": {}
+            }
+        return self.guilds[guild_id]
+
+    def record_play(self, guild_id: int, station_name: str, user_id: Optional[int], seconds: float):
+        """Record a finished playback session"""
+        stats = self._get(guild_id)
+        stats["total_plays"] += 1
+        stats["total_seconds"] += max(0, int(seconds))
+        stats["station_counts"][station_name] = stats["station_counts"].get(station_name, 0) + 1
+        self.global_station_counts[station_name] = self.global_station_counts.get(station_name, 0) + 1
+        if user_id is not None:
+            # Keys are strings so the stats survive a JSON round trip unchanged
+            user_key = str(user_id)
+            stats["user_counts"][user_key] = stats["user_counts"].get(user_key, 0) + 1
+
+    def get(self, guild_id: int) -> Optional[Dict]:
+        return self.guilds.get(guild_id)
+
+    def top_stations(self, guild_id: int, limit: int = 3) -> List[Tuple[str, int]]:
+        stats = self.guilds.get(guild_id)
+        if not stats:
+            return []
+        return sorted(stats["station_counts"].items(), key=lambda item: item[1], reverse=True)[:limit]
+
+    def global_top_stations(self, limit: int = 5) -> List[Tuple[str, int]]:
+        return sorted(self.global_station_counts.items(), key=lambda item: item[1], reverse=True)[:limit]
+
+    def top_user(self, guild_id: int) -> Optional[Tuple[int, int]]:
+        stats = self.guilds.get(guild_id)
+        if not stats or not stats["user_counts"]:
+            return None
+        user_key, count = max(stats["user_counts"].items(), key=lambda item: item[1])
+        return int(user_key), count
+
+    def reset(self, guild_id: int):
+        self.guilds.pop(guild_id, None)
+
+    def to_dict(self) -> Dict[str, Dict]:
+        return {
+            "guilds": {str(guild_id): stats for guild_id, stats in self.guilds.items()},
+            "global_station_counts": self.global_station_counts
+        }
+
+    def load(self, data: Dict[str, Dict]):
+        if "guilds" not in data:
+            # Older state files only stored the per-guild stats
+            data = {"guilds": data}
+        for guild_id_str, stats in data["guilds"].items():
+            self.guilds[int(guild_id_str)] = stats
+        if "global_station_counts" in data:
+            self.global_station_counts = data["global_station_counts"]
+        else:
+            for stats in self.guilds.values():
+                for station_name, count in stats["station_counts"].items():
+                    self.global_station_counts[station_name] = self.global_station_counts.get(station_name, 0) + count
+
+stats_tracker = StatsTracker()
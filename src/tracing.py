@@ -0,0 +1,146 @@
+import os
+import json
+import time
+import uuid
+import inspect
+import logging
+import functools
+import contextvars
+from typing import Optional
+
+logger = logging.getLogger(__name__)
+
+# Correlation ID of the interaction currently being handled ("-" outside of handlers)
+trace_id_var: contextvars.ContextVar[str] = contextvars.ContextVar("trace_id", default="-")
+# Fields of the enclosing @instrument spans (guild_id, command, station_name), attached to log records
+span_fields_var: contextvars.ContextVar[dict] = contextvars.ContextVar("span_fields", default={})
+
+_tracer = None  # OpenTelemetry tracer, only set when telemetry is enabled
+
+class TraceIdFilter(logging.Filter):
+    """Adds the current trace ID and span fields to every log record"""
+
+    def filter(self, record: logging.LogRecord) -> bool:
+        record.trace_id = trace_id_var.get()
+        record.span_fields = span_fields_var.get()
+        return True
+
+# Attributes every LogRecord has; anything else was passed via extra= and becomes a JSON key
+_STANDARD_RECORD_FIELDS = set(vars(logging.LogRecord("", 0, "", 0, "", (), None))) | {"message", "asctime", "trace_id", "span_fields"}
+
+class JsonFormatter(logging.Formatter):
+    """One JSON object per line, with span fields and extra= values as top-level keys"""
+
+    def format(self, record: logging.LogRecord) -> str:
+        entry = {
+            "timestamp": self.formatTime(record, "%Y-%m-%dT%H:%M:%S%z"),
+            "level": record.levelname,
+            "logger": record.name,
+            "message": record.getMessage(),
+            "trace_id": getattr(record, "trace_id", "-")
+        }
+        entry.update(getattr(record, "span_fields", {}))
+        entry.update({key: value for key, value in vars(record).items() if key not in _STANDARD_RECORD_FIELDS})
+        if record.exc_info:
+            entry["exception"] = self.formatException(record.exc_info)
+        return json.dumps(entry, default=str, ensure_ascii=False)
+
+LOG_FORMATS = {
+    "compact": lambda: logging.Formatter("%(asctime)s %(levelname).1s %(message)s", datefmt="%H:%M:%S"),
+    "json": JsonFormatter
+}
+
+def configure_log_format():
+    """
+    Switch the root handlers to the format in ALASTOR_LOG_FORMAT: "json" for log
+    ingestion (Loki, Datadog), "compact" for small terminals. Anything else keeps the default format.
+    """
+    log_format = os.getenv("ALASTOR_LOG_FORMAT", "").lower()
+    if log_format not in LOG_FORMATS:
+        if log_format:
+            logger.warning(f"⚠️  Unknown ALASTOR_LOG_FORMAT '{log_format}', using the default format")
+        return
+    for handler in logging.getLogger().handlers:
+        handler.setFormatter(LOG_FORMATS[log_format]())
+
+def install_log_filter():
+    """Attach the trace ID filter to all root handlers"""
+    for handler in logging.getLogger().handlers:
+        handler.addFilter(TraceIdFilter())
+
+def init_telemetry():
+    """
+    Export spans via OTLP when ALASTOR_OTEL_ENDPOINT is set.
+    OpenTelemetry is optional and only imported when telemetry is enabled.
+    """
+    global _tracer
+    endpoint = os.getenv("ALASTOR_OTEL_ENDPOINT")
+    if not endpoint:
+        return
+
+    try:
+        from opentelemetry import trace
+        from opentelemetry.sdk.resources import Resource
+        from opentelemetry.sdk.trace import TracerProvider
+        from opentelemetry.sdk.trace.export import BatchSpanProcessor
+        from opentelemetry.exporter.otlp.proto.http.trace_exporter import OTLPSpanExporter
+    except ImportError:
+        logger.warning("⚠️  ALASTOR_OTEL_ENDPOINT is set but OpenTelemetry is not installed. Install with: pip install opentelemetry-sdk opentelemetry-exporter-otlp-proto-http")
+        return
+
+    provider = TracerProvider(resource=Resource.create({"service.name": "alastor"}))
+    provider.add_span_processor(BatchSpanProcessor(OTLPSpanExporter(endpoint=endpoint)))
+    trace.set_tracer_provider(provider)
+    _tracer = trace.get_tracer("alastor")
+    logger.info(f"📡 OpenTelemetry tracing enabled (endpoint: {endpoint})")
+
+def _span_fields(arguments: dict) -> dict:
+    """Extract guild/command context from the first Interaction-like argument, plus a station_name argument"""
+    fields = {}
+    for value in arguments.values():
+        if hasattr(value, "guild_id") and hasattr(value, "response"):
+            command = getattr(value, "command", None)
+            fields = {
+                "guild_id": str(value.guild_id),
+                "command": getattr(command, "qualified_name", None) or "component"
+            }
+            break
+    if isinstance(arguments.get("station_name"), str):
+        fields["station_name"] = arguments["station_name"]
+    return fields
+
+def instrument(name: Optional[str] = None):
+    """
+    Wrap an async function in a trace span.
+    A new trace ID is created for the outermost span so all nested logs can be correlated.
+    """
+    def decorator(func):
+        span_name = name or func.__qualname__
+        signature = inspect.signature(func)
+
+        @functools.wraps(func)
+        async def wrapper(*args, **kwargs):
+            token = None
+            if trace_id_var.get() == "-":
+                token = trace_id_var.set(uuid.uuid4().hex[:8])
+            try:
+                arguments = signature.bind_partial(*args, **kwargs).arguments
+            except TypeError:
+                arguments = dict(kwargs)
+            fields = _span_fields(arguments)
+            fields_token = span_fields_var.set({**span_fields_var.get(), **fields})
+            started = time.perf_counter()
+            try:
+                if _tracer is None:
+                    return await func(*args, **kwargs)
+                with _tracer.start_as_current_span(span_name, attributes=fields):
+                    return await func(*args, **kwargs)
+            finally:
+                elapsed_ms = (time.perf_counter() - started) * 1000
+                logger.debug(f"span {span_name} {fields} took {elapsed_ms:.1f}ms")
+                span_fields_var.reset(fields_token)
+                if token is not None:
+                    trace_id_var.reset(token)
+
+        return wrapper
+    return decorator
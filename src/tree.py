@@ -0,0 +1,104 @@
+import time
+import logging
+import discord
+from discord import app_commands, Interaction, Embed
+from discord.ext import tasks
+import sys
+from pathlib import Path
+
+# Add the parent directory to sys.path to allow imports
+sys.path.insert(0, str(Path(__file__).parent.parent))
+
+from src.commands.radio import is_blacklisted, reject_blacklisted, safe_send_message, send_error_embed, component_cooldown
+from src.errors import is_user_facing
+from src.config import get_config
+from src.ratelimit import RateLimiter, CommandCooldown
+from src.latency import command_latencies
+
+logger = logging.getLogger(__name__)
+
+config = get_config()
+USER_RATE_LIMIT = config["bot"]["user_rate_limit_per_minute"]  # 0 disables the limit
+user_rate_limiter = RateLimiter(USER_RATE_LIMIT or 1, window=60)
+command_cooldown = CommandCooldown(config["bot"]["command_cooldowns"])
+
+def get_command_path(data: dict) -> str:
+    """Qualified command name like "radio play" from the raw interaction data (subcommand options have type 1 or 2)"""
+    parts = [data.get("name", "")]
+    options = data.get("options") or []
+    while options and options[0].get("type") in (1, 2):
+        parts.append(options[0]["name"])
+        options = options[0].get("options") or []
+    return " ".join(parts)
+
+def record_command_latency(interaction: Interaction):
+    """Record how long a slash command handler took (started in interaction_check)"""
+    started_at = interaction.extras.get("started_at")
+    if started_at is not None and interaction.command:
+        command_latencies.record_since(interaction.command.qualified_name, started_at)
+
+@tasks.loop(minutes=10)
+async def prune_rate_limiters():
+    """Forget users that haven't used a command for an hour"""
+    removed = user_rate_limiter.prune(3600)
+    if removed:
+        logger.debug(f"Pruned {removed} idle user rate limiters")
+    command_cooldown.prune()
+    component_cooldown.prune()
+
+class AlastorCommandTree(app_commands.CommandTree):
+    """Command tree that runs bot-wide checks before any slash command is dispatched"""
+
+    async def interaction_check(self, interaction: Interaction) -> bool:
+        command_name = (interaction.data or {}).get("name")
+        if interaction.type is discord.InteractionType.application_command:
+            interaction.extras["started_at"] = time.perf_counter()
+
+        # Blacklisted users may not control the radio
+        if command_name == "radio" and is_blacklisted(interaction.guild_id, interaction.user.id):
+            if interaction.type is discord.InteractionType.autocomplete:
+                return False
+            await reject_blacklisted(interaction)
+            return False
+
+        # Per-user rate limit across all servers and channels (autocomplete is not counted)
+        if USER_RATE_LIMIT and interaction.type is not discord.InteractionType.autocomplete:
+            retry_after = user_rate_limiter.hit(interaction.user.id)
+            if retry_after is not None:
+                logger.info(f"🐌 Rate limited {interaction.user} ({USER_RATE_LIMIT}/min)")
+                embed = Embed(
+                    title="🐌 Slow down!",
+                    description=f"Try again in a moment ({retry_after:.0f}s).",
+                    color=discord.Color.orange()
+                )
+                embed.set_footer(text="Alastor - The Radio Daemon")
+                await safe_send_message(interaction, embed=embed, ephemeral=True)
+                return False
+
+        # Per-user cooldown of single commands, e.g. against /radio play spam
+        if interaction.type is discord.InteractionType.application_command:
+            command_path = get_command_path(interaction.data or {})
+            retry_after = command_cooldown.hit(interaction.user.id, command_path)
+            if retry_after is not None:
+                logger.info(f"⏳ /{command_path} on cooldown for {interaction.user} ({retry_after:.1f}s left)")
+                embed = Embed(
+                    title="⏳ Cooldown",
+                    description=f"You must wait {retry_after:.1f}s before using this command again.",
+                    color=discord.Color.orange()
+                )
+                embed.set_footer(text="Alastor - The Radio Daemon")
+                await safe_send_message(interaction, embed=embed, ephemeral=True)
+                return False
+
+        return True
+
+    async def on_error(self, interaction: Interaction, error: app_commands.AppCommandError):
+        # Errors raised inside a command arrive wrapped in CommandInvokeError
+        original = getattr(error, "original", error)
+        command_name = interaction.command.qualified_name if interaction.command else "unknown"
+        record_command_latency(interaction)
+
+        # The traceback only helps for unexpected errors
+        logger.error(f"❌ /{command_name} failed for {interaction.user}: {original!r}",
+                     exc_info=None if is_user_facing(original) else original)
+        await send_error_embed(interaction, original)
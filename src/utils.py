@@ -0,0 +1,35 @@
+import re
+from typing import Optional
+
+DURATION_PATTERN = re.compile(r"^(?:(\d+)h)?(?:(\d+)m)?(?:(\d+)s)?$")
+
+def format_duration(seconds: float) -> str:
+    """Format a number of seconds as HH:MM:SS; negative values clamp to the zero output 00:00:00, hours may exceed 24"""
+    hours, remainder = divmod(max(int(seconds), 0), 3600)
+    minutes, seconds = divmod(remainder, 60)
+    return f"{hours:02d}:{minutes:02d}:{seconds:02d}"
+
+UPTIME_BLOCK_SECONDS = 600  # One block per 10 minutes
+UPTIME_BAR_WIDTH = 60  # 10 hours, then the bar starts over
+
+def format_uptime_bar(seconds: float) -> str:
+    """Uptime as a bar of █ (elapsed) and ░ blocks, e.g. 35 minutes -> ███░░░…"""
+    filled = int(max(seconds, 0) // UPTIME_BLOCK_SECONDS) % UPTIME_BAR_WIDTH
+    return "█" * filled + "░" * (UPTIME_BAR_WIDTH - filled)
+
+def format_ago(seconds: float) -> str:
+    """Format an age like 42s, 3m or 2h ago"""
+    seconds = max(int(seconds), 0)
+    if seconds < 60:
+        return f"{seconds}s ago"
+    if seconds < 3600:
+        return f"{seconds // 60}m ago"
+    return f"{seconds // 3600}h ago"
+
+def parse_duration_str(value: str) -> Optional[int]:
+    """Parse durations like "90s", "5m" or "2h30m" into seconds. Returns None if invalid."""
+    match = DURATION_PATTERN.match(value.strip().lower())
+    if not match or not any(match.groups()):
+        return None
+    hours, minutes, seconds = (int(group or 0) for group in match.groups())
+    return hours * 3600 + minutes * 60 + seconds
@@ -0,0 +1,42 @@
+import asyncio
+import logging
+from urllib.parse import urlparse
+
+logger = logging.getLogger(__name__)
+
+YOUTUBE_HOSTS = ("youtube.com", "youtu.be")
+EXTRACT_TIMEOUT = 20  # Seconds; yt-dlp has to load the page and player first
+
+def is_youtube_url(url: str) -> bool:
+    """YouTube (Music) URLs can't be streamed directly and need yt-dlp"""
+    host = (urlparse(url).hostname or "").lower()
+    return any(host == domain or host.endswith("." + domain) for domain in YOUTUBE_HOSTS)
+
+async def extract_stream_url(url: str, ytdlp_path: str) -> str:
+    """
+    Ask yt-dlp for the direct audio URL of a YouTube (live) stream.
+    Raises RuntimeError if yt-dlp fails, times out or is not installed.
+    """
+    try:
+        process = await asyncio.create_subprocess_exec(
+            ytdlp_path, "--get-url", "--format", "bestaudio/best", "--no-playlist", url,
+            stdout=asyncio.subprocess.PIPE,
+            stderr=asyncio.subprocess.PIPE
+        )
+    except OSError as e:
+        raise RuntimeError(f"Could not run yt-dlp at {ytdlp_path}: {e}")
+
+    try:
+        stdout, stderr = await asyncio.wait_for(process.communicate(), timeout=EXTRACT_TIMEOUT)
+    except asyncio.TimeoutError:
+        process.kill()
+        raise RuntimeError(f"yt-dlp did not answer within {EXTRACT_TIMEOUT}s")
+
+    if process.returncode != 0:
+        message = stderr.decode(errors="replace").strip().splitlines()
+        raise RuntimeError(message[-1] if message else f"yt-dlp exited with code {process.returncode}")
+    urls = stdout.decode(errors="replace").split()
+    if not urls:
+        raise RuntimeError("yt-dlp returned no stream URL")
+    logger.debug(f"yt-dlp resolved {url} to {urls[0]}")
+    return urls[0]
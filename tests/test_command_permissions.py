@@ -0,0 +1,36 @@
+import asyncio
+import unittest
+from unittest.mock import MagicMock, patch
+
+from src import command_permissions
+
+GUILD_ID = 1
+DJ_ROLE_ID = 2
+
+class BuildCommandPermissionsTest(unittest.TestCase):
+    def test_dj_role_allowed_and_everyone_denied(self):
+        self.assertEqual(command_permissions.build_command_permissions(GUILD_ID, DJ_ROLE_ID), {"permissions": [
+            {"id": "2", "type": 1, "permission": True},
+            {"id": "1", "type": 1, "permission": False},
+        ]})
+
+    def test_without_dj_role_everyone_sees_the_command(self):
+        self.assertEqual(command_permissions.build_command_permissions(GUILD_ID, None), {"permissions": []})
+
+class ApplyDjPermissionsTest(unittest.TestCase):
+    def test_disabled_by_default(self):
+        client = MagicMock()
+        with patch.object(command_permissions, "USE_COMMAND_PERMISSIONS", False), \
+                patch.object(command_permissions, "get_session") as get_session:
+            self.assertFalse(asyncio.run(command_permissions.apply_dj_permissions(client, GUILD_ID, DJ_ROLE_ID)))
+        get_session.assert_not_called()
+
+    def test_missing_token(self):
+        with patch.object(command_permissions, "USE_COMMAND_PERMISSIONS", True), \
+                patch.object(command_permissions, "PERMISSIONS_TOKEN", None), \
+                patch.object(command_permissions, "get_session") as get_session:
+            self.assertFalse(asyncio.run(command_permissions.apply_dj_permissions(MagicMock(), GUILD_ID, DJ_ROLE_ID)))
+        get_session.assert_not_called()
+
+if __name__ == "__main__":
+    unittest.main()
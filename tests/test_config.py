@@ -0,0 +1,148 @@
+import os
+import tempfile
+import unittest
+from unittest.mock import patch
+
+import yaml
+
+from src.config import (apply_env_overrides, check_tls, load_config, load_config_with_retry, read_config,
+                        save_stations, strip_unsafe_codec_args, validate_config)
+from src.errors import ConfigError
+
+CONFIG = """
+radios:
+  Broken:
+    url: "https://example.com/stream.mp3"
+    description: "Test"
+    thumbnail: "not a url"
+    max_listeners: "many"
+bot:
+  prefix: "!"
+"""
+
+class SaveStationsTest(unittest.TestCase):
+    def setUp(self):
+        handle, self.path = tempfile.mkstemp(suffix=".yaml")
+        with os.fdopen(handle, "w") as f:
+            f.write(CONFIG)
+        self.addCleanup(os.remove, self.path)
+
+    def test_save_stations_keeps_other_entries_as_written(self):
+        save_stations(self.path, {"New": {"url": "https://example.com/new.mp3", "description": None}})
+        with open(self.path, encoding="utf-8") as f:
+            radios = yaml.safe_load(f)["radios"]
+        self.assertEqual(radios["Broken"]["thumbnail"], "not a url")
+        self.assertEqual(radios["Broken"]["max_listeners"], "many")
+        self.assertEqual(radios["New"], {"url": "https://example.com/new.mp3"})
+
+    def test_save_stations_removes_stations(self):
+        save_stations(self.path, {"Broken": None})
+        with open(self.path, encoding="utf-8") as f:
+            self.assertEqual(yaml.safe_load(f)["radios"], {})
+
+class ReadConfigTest(unittest.TestCase):
+    def setUp(self):
+        handle, self.path = tempfile.mkstemp(suffix=".yaml")
+        with os.fdopen(handle, "w") as f:
+            f.write(CONFIG)
+        self.addCleanup(os.remove, self.path)
+
+    def test_validation_sees_the_raw_values(self):
+        messages = [problem.message for problem in validate_config(read_config(self.path))]
+        self.assertIn("invalid thumbnail URL `not a url`", messages)
+        self.assertIn("`max_listeners` must be a positive number, got `many`", messages)
+
+    def test_load_config_drops_invalid_values(self):
+        station = load_config(self.path)["radios"]["Broken"]
+        self.assertIsNone(station["thumbnail"])
+        self.assertIsNone(station["max_listeners"])
+
+class CheckTlsTest(unittest.TestCase):
+    def test_tls_station_needs_https(self):
+        with self.assertRaises(ConfigError):
+            check_tls({"radios": {"Secure FM": {"url": "http://example.com/stream.mp3", "tls": True}}})
+        check_tls({"radios": {"Secure FM": {"url": "https://example.com/stream.mp3", "tls": True}}})
+
+    def test_tls_station_mirrors_need_https(self):
+        with self.assertRaises(ConfigError):
+            check_tls({"radios": {"Secure FM": {"url": "https://example.com/stream.mp3", "tls": True,
+                                                "retry_urls": ["http://mirror.example.com/stream.mp3"]}}})
+
+    def test_http_url_without_tls_is_only_a_warning(self):
+        config = {"radios": {"Plain FM": {"url": "http://example.com/stream.mp3", "description": "Test"}}}
+        check_tls(config)
+        messages = [problem.message for problem in validate_config(config)]
+        self.assertIn("insecure `http://` URL; use `https://` if the station supports it", messages)
+
+class CodecArgsTest(unittest.TestCase):
+    def test_http_proxy_and_its_value_are_removed(self):
+        args = ["-f", "mpegts", "-http_proxy", "http://proxy.example.com:3128", "-analyzeduration", "0"]
+        self.assertEqual(strip_unsafe_codec_args(args), ["-f", "mpegts", "-analyzeduration", "0"])
+
+    def test_socks_proxy_is_removed(self):
+        self.assertEqual(strip_unsafe_codec_args(["-socks_proxy", "socks5://127.0.0.1:9050", "-re"]), ["-re"])
+
+    def test_proxy_without_value(self):
+        # The next option is kept, not mistaken for the proxy's value
+        self.assertEqual(strip_unsafe_codec_args(["-http_proxy", "-f", "mpegts"]), ["-f", "mpegts"])
+        self.assertEqual(strip_unsafe_codec_args(["-f", "mpegts", "-http_proxy"]), ["-f", "mpegts"])
+
+    def test_proxy_is_reported(self):
+        config = {"radios": {"Proxy FM": {"url": "https://example.com/stream.mp3", "description": "Test",
+                                          "codec_args": ["-http_proxy", "http://proxy.example.com:3128"]}}}
+        messages = [problem.message for problem in validate_config(config)]
+        self.assertIn("`codec_args` may not set a proxy (`-http_proxy`, `-socks_proxy`); ignoring those options", messages)
+
+    def test_non_list_is_dropped_with_a_warning(self):
+        handle, path = tempfile.mkstemp(suffix=".yaml")
+        with os.fdopen(handle, "w") as f:
+            f.write('radios:\n  Raw:\n    url: "https://example.com/stream.mp3"\n    description: "Test"\n'
+                    '    codec_args: "-f mpegts"\n')
+        self.addCleanup(os.remove, path)
+        messages = [problem.message for problem in validate_config(read_config(path))]
+        self.assertIn("`codec_args` must be a list of FFmpeg options like `[\"-f\", \"mpegts\"]`", messages)
+        self.assertIsNone(load_config(path)["radios"]["Raw"]["codec_args"])
+
+class ApplyEnvOverridesTest(unittest.TestCase):
+    def test_prefix_override(self):
+        config = yaml.safe_load(CONFIG)
+        apply_env_overrides(config, environ={"ALASTOR_BOT__PREFIX": "?", "ALASTOR_STARTUP_RETRIES": "3", "BOT_PREFIX": "$"})
+        self.assertEqual(config["bot"]["prefix"], "?")
+        self.assertNotIn("startup_retries", config["bot"])
+
+    def test_missing_section_is_created(self):
+        config = {"radios": {}}
+        apply_env_overrides(config, environ={"ALASTOR_BOT__PREFIX": "?"})
+        self.assertEqual(config["bot"], {"prefix": "?"})
+
+class EnvPrecedenceTest(unittest.TestCase):
+    def setUp(self):
+        handle, self.path = tempfile.mkstemp(suffix=".yaml")
+        with os.fdopen(handle, "w") as f:
+            f.write(CONFIG)
+        self.addCleanup(os.remove, self.path)
+        environ = {name: value for name, value in os.environ.items() if not name.startswith(("BOT_", "ALASTOR_", "DISCORD_"))}
+        patcher = patch.dict(os.environ, environ, clear=True)
+        patcher.start()
+        self.addCleanup(patcher.stop)
+
+    def test_alastor_prefix_overrides_the_file(self):
+        os.environ["ALASTOR_BOT__PREFIX"] = "?"
+        self.assertEqual(load_config(self.path)["bot"]["prefix"], "?")
+
+    def test_bot_prefix_wins_with_a_warning(self):
+        os.environ.update({"ALASTOR_BOT__PREFIX": "?", "BOT_PREFIX": "$"})
+        with self.assertLogs("src.config", "WARNING") as logs:
+            self.assertEqual(load_config(self.path)["bot"]["prefix"], "$")
+        self.assertIn("Both BOT_PREFIX and ALASTOR_BOT__PREFIX are set; using BOT_PREFIX", logs.output[0])
+
+    def test_discord_token_without_prefix(self):
+        os.environ["DISCORD_TOKEN"] = "token"
+        config = load_config_with_retry(self.path, max_attempts=1, required_env=("DISCORD_TOKEN",))
+        self.assertNotIn("discord_token", config["bot"])
+        del os.environ["DISCORD_TOKEN"]
+        with self.assertRaises(ConfigError):
+            load_config_with_retry(self.path, max_attempts=1, required_env=("DISCORD_TOKEN",))
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,60 @@
+import asyncio
+import unittest
+from unittest.mock import AsyncMock, patch
+
+from src.commands import radio
+from src.errors import PlaylistParseError, StreamUnavailableError
+
+PRIMARY = "https://primary.example.com/stream.pls"
+MIRROR = "https://mirror.example.com/stream"
+YOUTUBE = "https://www.youtube.com/watch?v=live"
+
+class OpenStreamTest(unittest.TestCase):
+    def open_stream(self, resolved: dict, healthy: dict, urls: list):
+        """Run open_stream with stubbed URL resolution and health checks; returns the result and the resolved URLs"""
+        async def resolve_stream_url(url):
+            result = resolved[url]
+            if isinstance(result, Exception):
+                raise result
+            return result
+
+        resolve = AsyncMock(side_effect=resolve_stream_url)
+        health = AsyncMock(side_effect=lambda url, station_name=None: (healthy[url], "" if healthy[url] else "HTTP 503"))
+        with patch.object(radio, "resolve_stream_url", resolve), patch.object(radio, "get_cached_health", health):
+            return asyncio.run(radio.open_stream("Jazz FM", urls)), [call.args[0] for call in resolve.call_args_list]
+
+    def test_broken_playlist_falls_back_to_mirror(self):
+        result, tried = self.open_stream(
+            {PRIMARY: PlaylistParseError(PRIMARY, "no stream entries"), MIRROR: MIRROR},
+            {MIRROR: True},
+            [PRIMARY, MIRROR],
+        )
+        self.assertEqual(result, (MIRROR, MIRROR))
+        self.assertEqual(tried, [PRIMARY, MIRROR])
+
+    def test_youtube_without_ytdlp_falls_back_to_mirror(self):
+        result, _ = self.open_stream(
+            {YOUTUBE: StreamUnavailableError("yt-dlp is not configured"), MIRROR: MIRROR},
+            {MIRROR: True},
+            [YOUTUBE, MIRROR],
+        )
+        self.assertEqual(result, (MIRROR, MIRROR))
+
+    def test_unhealthy_stream_falls_back_to_mirror(self):
+        result, _ = self.open_stream(
+            {PRIMARY: "https://primary.example.com/live", MIRROR: MIRROR},
+            {PRIMARY: False, MIRROR: True},
+            [PRIMARY, MIRROR],
+        )
+        self.assertEqual(result, (MIRROR, MIRROR))
+
+    def test_last_error_is_raised_when_nothing_works(self):
+        with self.assertRaises(PlaylistParseError):
+            self.open_stream(
+                {PRIMARY: None, MIRROR: PlaylistParseError(MIRROR, "no stream entries")},
+                {},
+                [PRIMARY, MIRROR],
+            )
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,172 @@
+import asyncio
+import unittest
+
+from src import playlist
+
+class FakeResponse:
+    def __init__(self, url: str, content_type: str = "audio/mpeg", text: str = ""):
+        self.url = url
+        self.headers = {"content-type": content_type}
+        self.history = []
+        self._text = text
+
+    def raise_for_status(self):
+        pass
+
+    async def text(self):
+        return self._text
+
+    async def __aenter__(self):
+        return self
+
+    async def __aexit__(self, *exc_info):
+        return False
+
+class FakeSession:
+    """Answers every request with the same response and records the methods used"""
+
+    def __init__(self, response: FakeResponse):
+        self.response = response
+        self.requests = []
+
+    def head(self, url, **kwargs):
+        self.requests.append(("HEAD", url))
+        return self.response
+
+    def get(self, url, **kwargs):
+        self.requests.append(("GET", url))
+        return self.response
+
+class ParsePlsTest(unittest.TestCase):
+    def test_entries_in_order(self):
+        entries = playlist.parse_pls(
+            "[playlist]\nNumberOfEntries=2\n"
+            "File2=https://b.example.com/stream\nTitle2=Backup\n"
+            "File1=https://a.example.com/stream\nTitle1=Main\nLength1=-1\n"
+        )
+        self.assertEqual([entry.url for entry in entries], ["https://a.example.com/stream", "https://b.example.com/stream"])
+        self.assertEqual((entries[0].title, entries[0].length), ("Main", -1))
+
+    def test_keys_are_case_insensitive(self):
+        entries = playlist.parse_pls("[playlist]\nfile1=https://a.example.com/stream\nLENGTH1=abc\n")
+        self.assertEqual(entries[0].url, "https://a.example.com/stream")
+        self.assertIsNone(entries[0].length)
+
+    def test_live_stream_is_preferred(self):
+        entries = playlist.parse_pls(
+            "[playlist]\nFile1=https://a.example.com/song.mp3\nLength1=180\n"
+            "File2=https://b.example.com/live\nLength2=-1\n"
+        )
+        self.assertEqual(playlist.select_pls_entry(entries).url, "https://b.example.com/live")
+
+    def test_no_entries(self):
+        with self.assertRaises(ValueError):
+            playlist.parse_pls("[playlist]\nFile1=file:///etc/passwd\n")
+
+class ParseM3uExtendedTest(unittest.TestCase):
+    def test_plain(self):
+        entries = playlist.parse_m3u_extended("# comment\nhttps://a.example.com/stream\n\nhttps://b.example.com/stream\n")
+        self.assertEqual([entry.url for entry in entries], ["https://a.example.com/stream", "https://b.example.com/stream"])
+        self.assertIsNone(entries[0].title)
+
+    def test_extinf(self):
+        entries = playlist.parse_m3u_extended(
+            '#EXTM3U\n#EXTINF:-1 tvg-logo="https://example.com/logo.png",Jazz FM\nhttps://jazz.example.com/stream\n'
+        )
+        self.assertEqual(entries[0].title, "Jazz FM")
+        self.assertEqual(entries[0].duration, -1)
+        self.assertEqual(entries[0].attributes, {"tvg-logo": "https://example.com/logo.png"})
+
+    def test_metadata_does_not_leak_to_next_entry(self):
+        entries = playlist.parse_m3u_extended(
+            "#EXTINF:-1,Jazz FM\nhttps://jazz.example.com/stream\nhttps://other.example.com/stream\n"
+        )
+        self.assertIsNone(entries[1].title)
+
+    def test_live_stream_is_preferred(self):
+        entries = playlist.parse_m3u_extended(
+            "#EXTINF:200,Song\nhttps://a.example.com/song.mp3\n#EXTINF:-1,Live\nhttps://b.example.com/live\n"
+        )
+        self.assertEqual(playlist.select_m3u_entry(entries).title, "Live")
+        self.assertIsNone(playlist.select_m3u_entry([]))
+
+class ParseXspfTest(unittest.TestCase):
+    def test_locations_in_order(self):
+        urls = playlist.parse_xspf(
+            '<?xml version="1.0" encoding="UTF-8"?>'
+            '<playlist version="1" xmlns="http://xspf.org/ns/0/"><trackList>'
+            '<track><location>https://a.example.com/stream</location><title>Main</title></track>'
+            '<track><location> https://b.example.com/stream </location></track>'
+            '</trackList></playlist>'
+        )
+        self.assertEqual(urls, ["https://a.example.com/stream", "https://b.example.com/stream"])
+
+    def test_non_http_locations_are_skipped(self):
+        urls = playlist.parse_xspf(
+            "<playlist><trackList>"
+            "<track><location>file:///music/song.mp3</location></track>"
+            "<track><location>https://a.example.com/stream</location></track>"
+            "</trackList></playlist>"
+        )
+        self.assertEqual(urls, ["https://a.example.com/stream"])
+
+    def test_invalid(self):
+        for content in ("<playlist><trackList>", "<playlist></playlist>", "<asx></asx>",
+                        "<playlist><trackList><track><location>file:///a.mp3</location></track></trackList></playlist>"):
+            with self.assertRaises(ValueError, msg=content):
+                playlist.parse_xspf(content)
+
+class ParseAsxTest(unittest.TestCase):
+    def test_refs_in_order(self):
+        urls = playlist.parse_asx(
+            '<ASX version="3.0"><Title>Jazz FM</Title>'
+            '<Entry><REF HREF="https://a.example.com/stream"/></Entry>'
+            '<entry><ref href="https://b.example.com/stream"/></entry></ASX>'
+        )
+        self.assertEqual(urls, ["https://a.example.com/stream", "https://b.example.com/stream"])
+
+    def test_mms_is_rewritten_to_mmsh(self):
+        urls = playlist.parse_asx('<asx version="3.0"><entry><ref href="mms://media.example.com/live"/></entry></asx>')
+        self.assertEqual(urls, ["mmsh://media.example.com/live"])
+
+    def test_invalid(self):
+        for content in ("<asx><entry>", "<asx></asx>", '<asx><entry><ref href="rtsp://a.example.com/live"/></entry></asx>'):
+            with self.assertRaises(ValueError, msg=content):
+                playlist.parse_asx(content)
+
+class DetectPlaylistTypeTest(unittest.TestCase):
+    def test_extension(self):
+        self.assertEqual(playlist.detect_playlist_type("https://example.com/listen.PLS"), "pls")
+        self.assertEqual(playlist.detect_playlist_type("https://example.com/live.m3u8?token=1"), "m3u")
+        self.assertEqual(playlist.detect_playlist_type("https://example.com/radio.xspf"), "xspf")
+        self.assertEqual(playlist.detect_playlist_type("https://example.com/radio.asx"), "asx")
+
+    def test_content_type(self):
+        self.assertEqual(playlist.detect_playlist_type("https://example.com/listen", "audio/x-scpls; charset=utf-8"), "pls")
+        self.assertEqual(playlist.detect_playlist_type("https://example.com/listen", "application/vnd.apple.mpegurl"), "m3u")
+
+    def test_audio_stream(self):
+        self.assertIsNone(playlist.detect_playlist_type("https://example.com/stream", "audio/mpeg"))
+        self.assertIsNone(playlist.detect_playlist_type("https://example.com/stream"))
+
+class ResolveStreamUrlTest(unittest.TestCase):
+    def test_audio_stream_returns_final_url_without_get(self):
+        session = FakeSession(FakeResponse("https://cdn.example.com/stream"))
+        result = asyncio.run(playlist.resolve_stream_url("https://example.com/stream", session))
+        self.assertEqual(result, ("https://cdn.example.com/stream", None))
+        self.assertEqual(session.requests, [("HEAD", "https://example.com/stream")])
+
+    def test_playlist_by_content_type(self):
+        session = FakeSession(FakeResponse("https://example.com/listen", "audio/x-scpls",
+                                           "[playlist]\nFile1=https://a.example.com/stream\nTitle1=Main\n"))
+        result = asyncio.run(playlist.resolve_stream_url("https://example.com/listen", session))
+        self.assertEqual(result, ("https://a.example.com/stream", "Main"))
+
+    def test_playlist_by_extension_skips_head(self):
+        session = FakeSession(FakeResponse("https://example.com/radio.m3u", "text/plain", "https://a.example.com/stream\n"))
+        result = asyncio.run(playlist.resolve_stream_url("https://example.com/radio.m3u", session))
+        self.assertEqual(result, ("https://a.example.com/stream", None))
+        self.assertEqual(session.requests, [("GET", "https://example.com/radio.m3u")])
+
+if __name__ == "__main__":
+    unittest.main()
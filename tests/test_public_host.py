@@ -0,0 +1,88 @@
+import asyncio
+import unittest
+from unittest.mock import patch
+
+from src.commands import radio
+
+class IsPublicAddressTest(unittest.TestCase):
+    def test_public(self):
+        for address in ("93.184.216.34", "2606:2800:220:1:248:1893:25c8:1946"):
+            self.assertTrue(radio.is_public_address(address), address)
+
+    def test_not_public(self):
+        for address in ("127.0.0.1", "10.0.0.5", "172.16.0.1", "192.168.1.1", "169.254.169.254",
+                        "0.0.0.0", "224.0.0.1", "::1", "fe80::1%eth0", "fd00::1"):
+            self.assertFalse(radio.is_public_address(address), address)
+
+class IsPublicHostTest(unittest.TestCase):
+    def test_ip_literals(self):
+        self.assertTrue(asyncio.run(radio.is_public_host("https://93.184.216.34/")))
+        self.assertFalse(asyncio.run(radio.is_public_host("http://192.168.1.1/admin")))
+        self.assertFalse(asyncio.run(radio.is_public_host("http://[::1]:8000/")))
+
+    def test_localhost(self):
+        self.assertFalse(asyncio.run(radio.is_public_host("http://localhost:8080/")))
+
+    def test_other_schemes(self):
+        self.assertFalse(asyncio.run(radio.is_public_host("file:///etc/passwd")))
+        self.assertFalse(asyncio.run(radio.is_public_host("ftp://93.184.216.34/")))
+
+class FakeContent:
+    def __init__(self, body: bytes):
+        self.body = body
+
+    async def read(self, size: int) -> bytes:
+        chunk, self.body = self.body[:size], self.body[size:]
+        return chunk
+
+class FakeResponse:
+    def __init__(self, url: str, status: int = 200, headers: dict = None, body: bytes = b""):
+        self.url = url
+        self.status = status
+        self.headers = headers or {}
+        self.content = FakeContent(body)
+
+    async def __aenter__(self):
+        return self
+
+    async def __aexit__(self, *exc_info):
+        return False
+
+class FakeSession:
+    """Answers requests from a url -> FakeResponse map and records the requested URLs"""
+
+    def __init__(self, responses: dict):
+        self.responses = responses
+        self.requested = []
+
+    def request(self, method, url, **kwargs):
+        self.requested.append(url)
+        return self.responses.get(url) or FakeResponse(url, status=404)
+
+class FindHomepageImageTest(unittest.TestCase):
+    HOMEPAGE = "https://93.184.216.34/"
+
+    def find(self, responses: dict):
+        session = FakeSession(responses)
+        with patch.object(radio, "get_session", lambda: session):
+            return asyncio.run(radio.find_homepage_image(self.HOMEPAGE)), session.requested
+
+    def test_og_image(self):
+        page = b'<html><head><meta property="og:image" content="/logo.png"></head></html>'
+        image, _ = self.find({self.HOMEPAGE: FakeResponse(self.HOMEPAGE, headers={"content-type": "text/html"}, body=page)})
+        self.assertEqual(image, "https://93.184.216.34/logo.png")
+
+    def test_redirect_to_private_address_is_not_followed(self):
+        redirect = FakeResponse(self.HOMEPAGE, status=302, headers={"location": "http://169.254.169.254/latest/meta-data/"})
+        image, requested = self.find({self.HOMEPAGE: redirect})
+        self.assertIsNone(image)
+        self.assertEqual(requested, [self.HOMEPAGE])
+
+    def test_favicon_redirect_to_loopback_is_not_followed(self):
+        favicon = "https://93.184.216.34/favicon.ico"
+        image, requested = self.find({favicon: FakeResponse(favicon, status=301, headers={"location": "http://127.0.0.1/favicon.ico"})})
+        self.assertIsNone(image)
+        self.assertNotIn("http://127.0.0.1/favicon.ico", requested)
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,47 @@
+import asyncio
+import unittest
+from types import SimpleNamespace
+from unittest.mock import AsyncMock, patch
+
+from src.commands import radio
+from tests.test_station_lookup import use_stations
+
+GUILD_ID = 1
+STATION = {"url": "https://jazz.example.com/stream", "description": "Jazz"}
+
+class RestartStationTest(unittest.TestCase):
+    def setUp(self):
+        self.guild = SimpleNamespace(id=GUILD_ID)
+        self.radio_data = {"name": "Jazz FM", "station_url": STATION["url"], "text_channel_id": 42}
+        self.start_station = AsyncMock(side_effect=lambda *args, **kwargs: {})
+        self.report_station_gone = AsyncMock()
+        self.stop_station = AsyncMock()
+        for patcher in (patch.object(radio, "start_station", self.start_station),
+                        patch.object(radio, "report_station_gone", self.report_station_gone),
+                        patch.object(radio, "stop_station", self.stop_station)):
+            patcher.start()
+            self.addCleanup(patcher.stop)
+
+    def restart(self):
+        return asyncio.run(radio.restart_station(None, self.guild, None, self.radio_data))
+
+    def test_same_name(self):
+        use_stations(self, {"Jazz FM": STATION})
+        new_data = self.restart()
+        self.assertEqual(self.start_station.call_args.args[3], "Jazz FM")
+        self.assertEqual(new_data["text_channel_id"], 42)
+
+    def test_renamed_station_is_found_by_url(self):
+        use_stations(self, {"Jazz FM 24/7": STATION})
+        self.restart()
+        self.assertEqual(self.start_station.call_args.args[3], "Jazz FM 24/7")
+
+    def test_removed_station_is_reported(self):
+        use_stations(self, {"Smooth Radio": {"url": "https://smooth.example.com/stream"}})
+        self.assertIsNone(self.restart())
+        self.start_station.assert_not_called()
+        self.report_station_gone.assert_awaited_once()
+        self.stop_station.assert_awaited_once_with(None, GUILD_ID)
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,51 @@
+import json
+import os
+import tempfile
+import unittest
+from unittest.mock import patch
+
+from src.commands import radio
+
+AUTH = {"username": "source", "password": "old-secret"}
+
+class StateCredentialsTest(unittest.TestCase):
+    def setUp(self):
+        handle, self.path = tempfile.mkstemp(suffix=".json")
+        os.close(handle)
+        self.addCleanup(os.remove, self.path)
+        for patcher in (patch.object(radio, "STATE_FILE", self.path),
+                        patch.dict(radio.RADIOS, {"Private FM": {"url": "https://private.example.com/stream", "auth": AUTH}}, clear=True),
+                        patch.dict(radio.current_radios, {}, clear=True),
+                        patch.dict(radio.server_stations, {}, clear=True),
+                        patch.dict(radio.guild_settings, {}, clear=True),
+                        patch.dict(radio.resume_sessions, {}, clear=True),
+                        patch.object(radio, "disabled_stations", set())):
+            patcher.start()
+            self.addCleanup(patcher.stop)
+
+    def test_credentials_are_not_saved(self):
+        radio.save_state()
+        with open(self.path) as f:
+            state = json.load(f)
+        self.assertEqual(state["radios"]["Private FM"], {"url": "https://private.example.com/stream"})
+        self.assertNotIn("old-secret", json.dumps(state))
+        self.assertEqual(radio.RADIOS["Private FM"]["auth"], AUTH)
+
+    def test_credentials_come_from_the_config(self):
+        # A state file written before credentials were left out
+        with open(self.path, "w") as f:
+            json.dump({"radios": {"Private FM": {"url": "https://private.example.com/stream", "auth": AUTH}}}, f)
+        new_auth = {"username": "source", "password": "new-secret"}
+        radio.RADIOS["Private FM"] = {"url": "https://private.example.com/stream", "auth": new_auth}
+        radio.load_state()
+        self.assertEqual(radio.RADIOS["Private FM"]["auth"], new_auth)
+
+    def test_removed_credentials_stay_removed(self):
+        with open(self.path, "w") as f:
+            json.dump({"radios": {"Private FM": {"url": "https://private.example.com/stream", "auth": AUTH}}}, f)
+        radio.RADIOS["Private FM"] = {"url": "https://private.example.com/stream"}
+        radio.load_state()
+        self.assertNotIn("auth", radio.RADIOS["Private FM"])
+
+if __name__ == "__main__":
+    unittest.main()
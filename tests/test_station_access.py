@@ -0,0 +1,42 @@
+import unittest
+from types import SimpleNamespace
+from unittest.mock import patch
+
+from src.commands import radio
+from src.errors import ListenerLimitError, StationRestrictedError
+
+def make_member(dj: bool = False, admin: bool = False, bot: bool = False):
+    return SimpleNamespace(display_name="Listener", bot=bot, guild=SimpleNamespace(name="Test Server"), dj=dj, admin=admin)
+
+def make_channel(listeners: int):
+    return SimpleNamespace(name="radio", members=[make_member() for _ in range(listeners)] + [make_member(bot=True)])
+
+class CheckStationAccessTest(unittest.TestCase):
+    def setUp(self):
+        for patcher in (patch.object(radio, "has_dj_permission", lambda member: member.dj),
+                        patch.object(radio, "is_admin", lambda member: member.admin)):
+            patcher.start()
+            self.addCleanup(patcher.stop)
+
+    def test_unrestricted_station(self):
+        radio.check_station_access(make_member(), "Jazz FM", {"url": "https://jazz.example.com"}, make_channel(3))
+
+    def test_restricted_station_needs_dj(self):
+        station = {"url": "https://jazz.example.com", "requires_dj": True}
+        with self.assertRaises(StationRestrictedError):
+            radio.check_station_access(make_member(), "Jazz FM", station, make_channel(1))
+        radio.check_station_access(make_member(dj=True), "Jazz FM", station, make_channel(1))
+
+    def test_listener_limit_ignores_bots(self):
+        station = {"url": "https://jazz.example.com", "max_listeners": 2}
+        radio.check_station_access(make_member(), "Jazz FM", station, make_channel(1))
+        with self.assertRaises(ListenerLimitError) as context:
+            radio.check_station_access(make_member(), "Jazz FM", station, make_channel(2))
+        self.assertEqual((context.exception.listeners, context.exception.limit), (2, 2))
+
+    def test_admins_skip_listener_limit(self):
+        station = {"url": "https://jazz.example.com", "max_listeners": 2}
+        radio.check_station_access(make_member(admin=True), "Jazz FM", station, make_channel(5))
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,72 @@
+import unittest
+from unittest.mock import patch
+
+from src.commands import radio
+from src.config import validate_config
+
+GUILD_ID = 1
+STATIONS = {
+    "Jazz FM": {"url": "https://jazz.example.com/stream", "description": "Jazz", "aliases": ["Jazz FM"]},
+    "Smooth Radio": {"url": "https://smooth.example.com/stream", "description": "Smooth", "aliases": ["jazz fm", "smooth"]},
+}
+
+def use_stations(test: unittest.TestCase, stations: dict):
+    """Replace the global stations for the duration of a test"""
+    for patcher in (patch.dict(radio.RADIOS, stations, clear=True),
+                    patch.dict(radio.server_stations, {}, clear=True),
+                    patch.object(radio, "disabled_stations", set())):
+        patcher.start()
+        test.addCleanup(patcher.stop)
+
+class ResolveStationNameTest(unittest.TestCase):
+    def setUp(self):
+        use_stations(self, STATIONS)
+
+    def test_exact_name_wins_over_alias(self):
+        self.assertEqual(radio.resolve_station_name(GUILD_ID, "Jazz FM"), "Jazz FM")
+
+    def test_name_ignoring_case_wins_over_alias(self):
+        # "jazz fm" is also an alias of Smooth Radio
+        self.assertEqual(radio.resolve_station_name(GUILD_ID, "jazz fm"), "Jazz FM")
+
+    def test_alias(self):
+        self.assertEqual(radio.resolve_station_name(GUILD_ID, "SMOOTH"), "Smooth Radio")
+
+    def test_fuzzy_name_is_only_suggested(self):
+        self.assertIsNone(radio.resolve_station_name(GUILD_ID, "Jaz FM"))
+        self.assertEqual(radio.rank_stations(GUILD_ID, "Jaz FM")[0][0], "Jazz FM")
+
+    def test_alias_of_own_name_is_skipped(self):
+        use_stations(self, {"Jazz FM": STATIONS["Jazz FM"]})
+        self.assertIsNone(radio.find_station_by_alias(GUILD_ID, "jazz fm"))
+
+class SearchStationsTest(unittest.TestCase):
+    def setUp(self):
+        use_stations(self, STATIONS)
+
+    def test_alias_equal_to_another_station_name_is_listed_once(self):
+        # "Jazz FM" matches its own name and is an alias of Smooth Radio
+        results = radio.search_stations(GUILD_ID, "jazz fm")
+        self.assertEqual(sorted(results), ["Jazz FM", "Smooth Radio"])
+        self.assertEqual(results[0], "Jazz FM")
+
+    def test_description_only_match(self):
+        use_stations(self, {
+            "Radio Paradise": {"url": "https://paradise.example.com/stream", "description": "Eclectic rock and world music"},
+            "World FM": {"url": "https://world.example.com/stream", "description": "News"},
+        })
+        self.assertEqual(radio.search_stations(GUILD_ID, "Eclectic"), ["Radio Paradise"])
+        # Name matches rank above description matches
+        self.assertEqual(radio.search_stations(GUILD_ID, "world"), ["World FM", "Radio Paradise"])
+
+class AliasValidationTest(unittest.TestCase):
+    def test_alias_colliding_with_station_name(self):
+        messages = [str(warning) for warning in validate_config({"radios": STATIONS})]
+        self.assertIn("Smooth Radio: alias `jazz fm` is the name of **Jazz FM**", messages)
+
+    def test_alias_of_own_name_is_not_a_collision(self):
+        messages = [str(warning) for warning in validate_config({"radios": STATIONS})]
+        self.assertFalse(any(message.startswith("Jazz FM: alias") for message in messages))
+
+if __name__ == "__main__":
+    unittest.main()
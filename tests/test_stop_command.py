@@ -0,0 +1,54 @@
+import asyncio
+import unittest
+from typing import Dict, Optional
+from unittest.mock import AsyncMock, MagicMock, patch
+
+from src.commands import radio
+
+GUILD_ID = 1
+
+class FakeRadioManager(radio.RadioManager):
+    """Keeps the playing stations in a dict instead of voice connections"""
+
+    def __init__(self, playing: Dict[int, str]):
+        self.playing = dict(playing)
+        self.stopped = []
+
+    async def stop_station(self, client, guild_id: int) -> Optional[str]:
+        self.stopped.append(guild_id)
+        return self.playing.pop(guild_id, None)
+
+    def get_current_station(self, guild_id: int) -> Optional[str]:
+        return self.playing.get(guild_id)
+
+    def get_active_streams_count(self) -> int:
+        return len(self.playing)
+
+class HandleStopCommandTest(unittest.TestCase):
+    def setUp(self):
+        self.interaction = MagicMock(guild_id=GUILD_ID)
+        self.send = AsyncMock()
+        self.embed = MagicMock()
+        for patcher in (patch.object(radio, "safe_send_message", self.send),
+                        patch.object(radio, "Embed", self.embed)):
+            patcher.start()
+            self.addCleanup(patcher.stop)
+
+    def test_no_active_stream(self):
+        manager = FakeRadioManager({})
+        asyncio.run(radio.handle_stop_command(manager, self.interaction))
+        self.assertEqual(manager.stopped, [])
+        self.assertEqual(self.embed.call_args.kwargs["title"], "📻 No Radio Playing")
+        self.send.assert_awaited_once()
+
+    def test_successful_stop(self):
+        manager = FakeRadioManager({GUILD_ID: "Jazz FM", 2: "Smooth Radio"})
+        asyncio.run(radio.handle_stop_command(manager, self.interaction))
+        self.assertEqual(manager.stopped, [GUILD_ID])
+        self.assertEqual(manager.get_active_streams_count(), 1)
+        self.assertEqual(self.embed.call_args.kwargs["title"], "⏹️ Radio Stopped")
+        self.assertIn("**Jazz FM**", self.embed.call_args.kwargs["description"])
+        self.send.assert_awaited_once()
+
+if __name__ == "__main__":
+    unittest.main()
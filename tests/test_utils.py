@@ -0,0 +1,34 @@
+import unittest
+
+from src.utils import format_duration, parse_duration_str
+
+class FormatDurationTest(unittest.TestCase):
+    def test_zero(self):
+        self.assertEqual(format_duration(0), "00:00:00")
+
+    def test_one_minute(self):
+        self.assertEqual(format_duration(60), "00:01:00")
+
+    def test_one_hour(self):
+        self.assertEqual(format_duration(3600), "01:00:00")
+
+    def test_negative_is_clamped_to_zero(self):
+        for seconds in (-5, -0.5, -90000):
+            self.assertEqual(format_duration(seconds), format_duration(0), seconds)
+
+    def test_over_a_day(self):
+        self.assertEqual(format_duration(26 * 3600 + 61.9), "26:01:01")
+
+class ParseDurationStrTest(unittest.TestCase):
+    def test_valid(self):
+        self.assertEqual(parse_duration_str("90s"), 90)
+        self.assertEqual(parse_duration_str("5m"), 300)
+        self.assertEqual(parse_duration_str("2h30m"), 9000)
+        self.assertEqual(parse_duration_str(" 1H "), 3600)
+
+    def test_invalid(self):
+        for value in ("", "5", "m", "30m2h", "-5m", "1.5h"):
+            self.assertIsNone(parse_duration_str(value), value)
+
+if __name__ == "__main__":
+    unittest.main()